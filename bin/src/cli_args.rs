@@ -1,4 +1,4 @@
-use clap::{Parser, Subcommand};
+use clap::{ArgGroup, Args, Parser, Subcommand};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -17,6 +17,16 @@ pub enum CliSubCommands {
     Diff(DiffCliArgs),
     #[command(about = "Runs structured merge on the scenario provided")]
     Merge(MergeCliArgs),
+    #[command(
+        about = "Runs structured merge as a git merge driver",
+        long_about = "Runs structured merge as a git merge driver.\n\nArguments follow the placeholders git passes to custom merge drivers, so it can be configured as `driver = last-merge git-driver %O %A %B %L %P`. The result is written back into the current (%A) file."
+    )]
+    GitDriver(GitDriverCliArgs),
+    #[command(
+        about = "Runs structured merge with the same interface as git merge-file",
+        long_about = "Runs structured merge with the same interface as git merge-file.\n\nIncorporates all changes that lead from <BASE_PATH> to <RIGHT_PATH> into <LEFT_PATH>. Unless -p is given, the result is written back into <LEFT_PATH>."
+    )]
+    MergeFile(MergeFileCliArgs),
 }
 
 #[derive(Parser, Debug)]
@@ -35,6 +45,39 @@ pub struct DiffCliArgs {
     pub(crate) language: Option<String>,
 }
 
+/// Options shared by the subcommands that merge files
+#[derive(Args, Debug)]
+pub struct MergeOptions {
+    /// The language that the files being merged are written in.
+    /// If not provided the language will try to be inferred by the extension of the merged file.
+    #[arg(long)]
+    pub(crate) language: Option<String>,
+
+    /// Path to a TOML or JSON file describing the language, used instead of the built-in
+    /// configuration for files with one of the extensions it lists. A definition listing no
+    /// extensions is used for every file.
+    #[arg(long, value_name = "PATH", conflicts_with = "language")]
+    pub(crate) language_definition: Option<std::path::PathBuf>,
+
+    /// Falls back to a textual merge when structured merge fails, the files have syntax errors
+    /// or the structured result has more conflicts than the textual one
+    #[arg(long)]
+    pub(crate) hybrid: bool,
+
+    /// Re-parses the merged output and reports syntax errors introduced by the merge
+    #[arg(long)]
+    pub(crate) validate: bool,
+
+    /// Writes a JSON report with the location of each conflict and the time spent on each step
+    #[arg(long, value_name = "PATH")]
+    pub(crate) report: Option<std::path::PathBuf>,
+
+    /// What to do when an input file has syntax errors: "abort", "fallback" to a textual merge,
+    /// or "continue" with structured merge. Hybrid mode always falls back.
+    #[arg(long, default_value = "continue")]
+    pub(crate) on_syntax_error: bin::SyntaxErrorPolicy,
+}
+
 #[derive(Parser, Debug)]
 pub struct MergeCliArgs {
     /// Path to file in base revision
@@ -53,12 +96,144 @@ pub struct MergeCliArgs {
     #[arg(short, long)]
     pub(crate) merge_path: std::path::PathBuf,
 
-    /// The language that the files being diffed are written in.
-    /// If not provided the language will try to be inferred by the extension.
-    #[arg(long)]
-    pub(crate) language: Option<String>,
-
     /// Prints a detailed log of the merge process in the CST nodes
     #[arg(long)]
     pub print_chunks: bool,
+
+    /// How `--print-chunks` shows the chunks: "readable", with a preview of each node, or "text",
+    /// one tab separated line per chunk
+    #[arg(long, default_value = "readable")]
+    pub(crate) chunk_log_format: bin::ChunkLogFormat,
+
+    /// Writes the log of the merge process in the CST nodes as JSON
+    #[arg(long, value_name = "PATH")]
+    pub(crate) chunk_log: Option<std::path::PathBuf>,
+
+    /// The style of conflict markers: "merge" or "diff3", which also shows the base version.
+    /// Defaults to the one in the repository configuration, or "merge".
+    #[arg(long)]
+    pub(crate) conflict_style: Option<merge::ConflictStyle>,
+
+    #[command(flatten)]
+    pub(crate) options: MergeOptions,
+}
+
+#[derive(Parser, Debug)]
+pub struct GitDriverCliArgs {
+    /// Path to the temporary file holding the ancestor's version (%O)
+    pub(crate) base_path: std::path::PathBuf,
+
+    /// Path to the temporary file holding the current version (%A).
+    /// The merge result is written back into this file.
+    pub(crate) left_path: std::path::PathBuf,
+
+    /// Path to the temporary file holding the other branch's version (%B)
+    pub(crate) right_path: std::path::PathBuf,
+
+    /// The conflict marker size (%L)
+    pub(crate) marker_size: usize,
+
+    /// The pathname in which the merged result will be stored (%P).
+    /// Used to infer the language, as the temporary files carry no extension.
+    pub(crate) path_name: std::path::PathBuf,
+
+    /// The style of conflict markers: "merge" or "diff3", which also shows the base version.
+    /// Defaults to the one in the repository configuration, or "merge".
+    #[arg(long)]
+    pub(crate) conflict_style: Option<merge::ConflictStyle>,
+
+    #[command(flatten)]
+    pub(crate) options: MergeOptions,
+}
+
+#[derive(Parser, Debug)]
+#[command(group(ArgGroup::new("favor").args(["ours", "theirs", "union"])))]
+pub struct MergeFileCliArgs {
+    /// Labels used in conflict markers, in the order current, base and other.
+    /// The file names are used for any label not provided.
+    #[arg(short = 'L', value_name = "LABEL", num_args = 1, action = clap::ArgAction::Append)]
+    pub(crate) labels: Vec<String>,
+
+    /// Send the result to standard output instead of overwriting the current file
+    #[arg(short = 'p', long = "stdout")]
+    pub(crate) stdout: bool,
+
+    /// Do not warn about conflicts
+    #[arg(short, long)]
+    pub(crate) quiet: bool,
+
+    /// Use this many characters for conflict markers
+    #[arg(long, default_value_t = merge::DEFAULT_MARKER_SIZE)]
+    pub(crate) marker_size: usize,
+
+    /// Show the base version of conflicting nodes in a "|||||||" section
+    #[arg(long)]
+    pub(crate) diff3: bool,
+
+    /// Resolve conflicts favoring our (current) side
+    #[arg(long)]
+    pub(crate) ours: bool,
+
+    /// Resolve conflicts favoring their (other) side
+    #[arg(long)]
+    pub(crate) theirs: bool,
+
+    /// Resolve conflicts keeping the contents of both sides
+    #[arg(long)]
+    pub(crate) union: bool,
+
+    #[command(flatten)]
+    pub(crate) options: MergeOptions,
+
+    /// Path to file in current revision
+    pub(crate) left_path: std::path::PathBuf,
+
+    /// Path to file in base revision
+    pub(crate) base_path: std::path::PathBuf,
+
+    /// Path to file in other revision
+    pub(crate) right_path: std::path::PathBuf,
+}
+
+impl MergeFileCliArgs {
+    pub(crate) fn favor(&self) -> Option<merge::ConflictFavor> {
+        if self.ours {
+            Some(merge::ConflictFavor::Ours)
+        } else if self.theirs {
+            Some(merge::ConflictFavor::Theirs)
+        } else if self.union {
+            Some(merge::ConflictFavor::Union)
+        } else {
+            None
+        }
+    }
+
+    /// Labels given in the command line come first, then the ones in the repository
+    /// configuration and then the file names
+    pub(crate) fn render_options(
+        &self,
+        repository_config: &crate::repository_config::RepositoryConfig,
+    ) -> merge::RenderOptions {
+        let label = |index: usize, configured: &Option<String>, path: &std::path::Path| {
+            self.labels
+                .get(index)
+                .or(configured.as_ref())
+                .cloned()
+                .unwrap_or_else(|| path.display().to_string())
+        };
+        let configured_labels = &repository_config.labels;
+
+        merge::RenderOptions {
+            left_label: Some(label(0, &configured_labels.left, &self.left_path)),
+            base_label: Some(label(1, &configured_labels.base, &self.base_path)),
+            right_label: Some(label(2, &configured_labels.right, &self.right_path)),
+            marker_size: self.marker_size,
+            style: if self.diff3 {
+                merge::ConflictStyle::Diff3
+            } else {
+                repository_config.conflict_style.unwrap_or_default()
+            },
+            favor: self.favor(),
+        }
+    }
 }
@@ -1,10 +1,16 @@
 pub const SUCCESS_WITHOUT_CONFLICTS: i32 = 0;
 pub const SUCCESS_WITH_CONFLICTS: i32 = 1;
+/// Like `git merge-file`, the `merge-file` subcommand exits with its number of conflicts, which is
+/// capped so it is never taken for an error
+pub const MAX_CONFLICTS_EXIT_CODE: i32 = 127;
 
 pub const SUCCESS_FILES_FULLY_MATCH: i32 = 0;
 pub const SUCCESS_FILES_DO_NOT_FULLY_MATCH: i32 = 1;
 
+// Error codes are above 128 so that git treats them as a failed merge driver
+// execution rather than as a merge with conflicts.
 pub const READING_FILE_ERROR: i32 = 129;
 pub const INVALID_LANGUAGE_ERROR: i32 = 130;
 pub const WRITING_FILE_ERROR: i32 = 131;
 pub const INTERNAL_EXECUTION_ERROR: i32 = 132;
+pub const INVALID_CONFIGURATION_ERROR: i32 = 133;
@@ -5,27 +5,125 @@ use std::{
 };
 
 use matching::MatchingEntry;
-use merge::log_structures::{LogState, MergeChunk};
-use parsing::ParserConfiguration;
+use merge::{
+    log_structures::{LogState, MergeChunk},
+    ChunkLog, ConflictLocation, RenderOptions,
+};
+use parsing::{
+    ConfigurationOverrides, ConfigurationOverridesError, LanguageDefinition,
+    LanguageDefinitionError, ParsedTree, ParserConfiguration, SyntaxDiagnostic,
+};
+
+use crate::{
+    report::{ConflictMerge, ConflictReport, MergeReport, Timings},
+    semantic_checks::{run_semantic_checks, SourceTrees},
+    validation::validate_merged_output_with_configuration,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Revision {
+    Base,
+    Left,
+    Right,
+}
+
+impl Display for Revision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Revision::Base => write!(f, "base"),
+            Revision::Left => write!(f, "left"),
+            Revision::Right => write!(f, "right"),
+        }
+    }
+}
+
+/// What to do when an input file has syntax errors
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SyntaxErrorPolicy {
+    /// Fail with `ExecutionError::InputWithSyntaxErrors`
+    Abort,
+    /// Use a textual merge instead
+    Fallback,
+    /// Run structured merge on the trees the parser recovered
+    #[default]
+    Continue,
+}
+
+impl std::str::FromStr for SyntaxErrorPolicy {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "abort" => Ok(SyntaxErrorPolicy::Abort),
+            "fallback" => Ok(SyntaxErrorPolicy::Fallback),
+            "continue" => Ok(SyntaxErrorPolicy::Continue),
+            _ => Err(format!("Invalid syntax error policy provided: {value}")),
+        }
+    }
+}
+
+/// How `print_chunks` shows the chunks of the merge
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChunkLogFormat {
+    /// A block per chunk, with a preview of the contents of its nodes
+    #[default]
+    Readable,
+    /// One tab separated line per chunk, see [`ChunkLog::to_compact_text`]
+    Text,
+}
+
+impl std::str::FromStr for ChunkLogFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "readable" => Ok(ChunkLogFormat::Readable),
+            "text" => Ok(ChunkLogFormat::Text),
+            _ => Err(format!("Invalid chunk log format provided: {value}")),
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum ExecutionError {
-    ParsingError(&'static str),
+    ParsingError(parsing::ParseError),
+    InputWithSyntaxErrors(Vec<(Revision, SyntaxDiagnostic)>),
     MergeError(merge::MergeError),
+    InvalidLanguageDefinition(LanguageDefinitionError),
+    InvalidOverrides(ConfigurationOverridesError),
 }
 
 impl fmt::Display for ExecutionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             ExecutionError::ParsingError(error) => write!(f, "Parsing error occurred: {error}"),
+            ExecutionError::InputWithSyntaxErrors(diagnostics) => {
+                write!(f, "Input files have syntax errors:")?;
+                for (revision, diagnostic) in diagnostics {
+                    write!(f, "\n  {revision}: {diagnostic}")?;
+                }
+                Ok(())
+            }
             ExecutionError::MergeError(error) => write!(f, "Merge error occurred: {error}"),
+            ExecutionError::InvalidLanguageDefinition(error) => {
+                write!(f, "Invalid language definition: {error}")
+            }
+            ExecutionError::InvalidOverrides(error) => {
+                write!(f, "Invalid configuration overrides: {error}")
+            }
         }
     }
 }
 
 impl Error for ExecutionError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        None
+        match self {
+            ExecutionError::ParsingError(error) => Some(error),
+            ExecutionError::MergeError(error) => Some(error),
+            ExecutionError::InvalidLanguageDefinition(error) => Some(error),
+            ExecutionError::InvalidOverrides(error) => Some(error),
+            ExecutionError::InputWithSyntaxErrors(_) => None,
+        }
     }
 }
 
@@ -44,49 +142,478 @@ impl Display for ExecutionResult {
     }
 }
 
+impl ExecutionResult {
+    fn new(value: String, has_conflict: bool, config: &ExecutionConfig) -> Self {
+        match has_conflict && config.render_options.favor.is_none() {
+            true => ExecutionResult::WithConflicts(value),
+            false => ExecutionResult::WithoutConflicts(value),
+        }
+    }
+}
+
+/// Why the structured merge was discarded in favor of the textual one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FallbackReason {
+    ExcludedFromStructuredMerge,
+    InputWithSyntaxErrors,
+    StructuredMergeFailed(String),
+    MergedOutputDoesNotParse,
+    MoreConflictsThanTextualMerge { structured: usize, textual: usize },
+}
+
+impl Display for FallbackReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FallbackReason::ExcludedFromStructuredMerge => {
+                write!(f, "the file is excluded from structured merge")
+            }
+            FallbackReason::InputWithSyntaxErrors => {
+                write!(f, "at least one of the input files has syntax errors")
+            }
+            FallbackReason::StructuredMergeFailed(error) => {
+                write!(f, "structured merge failed: {error}")
+            }
+            FallbackReason::MergedOutputDoesNotParse => {
+                write!(f, "the structured merge output does not parse")
+            }
+            FallbackReason::MoreConflictsThanTextualMerge {
+                structured,
+                textual,
+            } => write!(
+                f,
+                "structured merge has {structured} conflicts while textual merge has {textual}"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeStrategy {
+    Structured,
+    Textual(FallbackReason),
+}
+
+impl Display for MergeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeStrategy::Structured => write!(f, "structured"),
+            MergeStrategy::Textual(reason) => write!(f, "textual ({reason})"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct HybridExecutionResult {
+    pub result: ExecutionResult,
+    pub strategy: MergeStrategy,
+    pub report: MergeReport,
+}
+
+#[derive(Debug, Default)]
+pub struct ExecutionConfig {
+    pub print_chunks: bool,
+    pub chunk_log_format: ChunkLogFormat,
+    /// Keeps the chunks of the merge in the report
+    pub record_chunk_log: bool,
+    pub render_options: RenderOptions,
+    pub on_syntax_error: SyntaxErrorPolicy,
+    /// Adjusts the configuration of the language being merged
+    pub overrides: ConfigurationOverrides,
+}
+
+/// The language of the files being merged
+#[derive(Debug, Clone)]
+pub enum MergeLanguage {
+    BuiltIn(model::Language),
+    /// Described in a definition file, replacing the built-in configuration
+    Defined(Box<LanguageDefinition>),
+}
+
+impl From<model::Language> for MergeLanguage {
+    fn from(language: model::Language) -> Self {
+        MergeLanguage::BuiltIn(language)
+    }
+}
+
+impl From<LanguageDefinition> for MergeLanguage {
+    fn from(definition: LanguageDefinition) -> Self {
+        MergeLanguage::Defined(Box::new(definition))
+    }
+}
+
+impl MergeLanguage {
+    pub fn parser_configuration(&self) -> Result<ParserConfiguration, LanguageDefinitionError> {
+        match self {
+            MergeLanguage::BuiltIn(language) => Ok(ParserConfiguration::from(*language)),
+            MergeLanguage::Defined(definition) => {
+                ParserConfiguration::try_from(definition.as_ref())
+            }
+        }
+    }
+
+    /// The built-in language whose grammar is used, if any
+    pub fn built_in(&self) -> Option<model::Language> {
+        match self {
+            MergeLanguage::BuiltIn(language) => Some(*language),
+            MergeLanguage::Defined(definition) => definition.built_in_grammar(),
+        }
+    }
+}
+
+struct StructuredMergeOutput {
+    rendered: String,
+    conflict_locations: Vec<ConflictLocation>,
+    conflicts: Vec<ConflictReport>,
+    timings: Timings,
+    chunk_log: Option<ChunkLog>,
+}
+
+impl StructuredMergeOutput {
+    /// Conflicts raised by the merge itself. The ones raised by semantic checks are left out, as
+    /// a textual merge would not see those problems at all.
+    fn merge_conflict_count(&self) -> usize {
+        self.conflicts
+            .iter()
+            .filter(|conflict| conflict.merge != ConflictMerge::FieldNumberClash)
+            .count()
+    }
+
+    fn into_result(self, config: &ExecutionConfig) -> (ExecutionResult, MergeReport) {
+        let mut report = MergeReport::new(&MergeStrategy::Structured, self.conflicts, self.timings);
+        report.chunk_log = self.chunk_log;
+        finish_execution(self.rendered, &self.conflict_locations, report, config)
+    }
+}
+
+fn textual_result(
+    textual_merge: merge::MergedCSTNode,
+    reason: FallbackReason,
+    timings: Timings,
+    config: &ExecutionConfig,
+) -> (ExecutionResult, MergeReport) {
+    let report = MergeReport::new(
+        &MergeStrategy::Textual(reason),
+        MergeReport::textual_conflicts(textual_merge.conflict_count()),
+        timings,
+    );
+    let (rendered, conflict_locations) =
+        textual_merge.render_locating_conflicts(&config.render_options);
+    finish_execution(rendered, &conflict_locations, report, config)
+}
+
+fn finish_execution(
+    rendered: String,
+    conflict_locations: &[ConflictLocation],
+    mut report: MergeReport,
+    config: &ExecutionConfig,
+) -> (ExecutionResult, MergeReport) {
+    report.locate_conflicts_in_output(conflict_locations);
+    let has_conflict = report.conflict_count > 0;
+    (ExecutionResult::new(rendered, has_conflict, config), report)
+}
+
+fn run_trivial_merge(base: &str, left: &str, right: &str) -> Option<ExecutionResult> {
+    if base == left {
+        log::info!("Early returning because base equals left");
+        return Some(ExecutionResult::WithoutConflicts(right.to_string()));
+    }
+
+    if base == right {
+        log::info!("Early returning because base equals right");
+        return Some(ExecutionResult::WithoutConflicts(left.to_string()));
+    }
+
+    None
+}
+
 pub fn run_tool_on_merge_scenario(
-    language: model::Language,
+    language: impl Into<MergeLanguage>,
     base: &str,
     left: &str,
     right: &str,
-    print_chunks: bool,
+    config: &ExecutionConfig,
 ) -> Result<ExecutionResult, ExecutionError> {
-    if base == left {
-        log::info!("Early returning because base equals left");
-        return Ok(ExecutionResult::WithoutConflicts(right.to_string()));
+    run_tool_on_merge_scenario_with_report(language, base, left, right, config)
+        .map(|(result, _)| result)
+}
+
+/// Runs only the textual merge, for files that should not be merged structurally
+pub fn run_textual_merge_on_scenario(
+    base: &str,
+    left: &str,
+    right: &str,
+    config: &ExecutionConfig,
+) -> (ExecutionResult, MergeReport) {
+    if let Some(result) = run_trivial_merge(base, left, right) {
+        return (result, MergeReport::default());
     }
 
-    if base == right {
-        log::info!("Early returning because base equals right");
-        return Ok(ExecutionResult::WithoutConflicts(left.to_string()));
+    textual_result(
+        merge::textual_merge(base, left, right),
+        FallbackReason::ExcludedFromStructuredMerge,
+        Timings::default(),
+        config,
+    )
+}
+
+/// Same as [`run_tool_on_merge_scenario`], also returning a report of where the conflicts are
+/// and how long each step took
+pub fn run_tool_on_merge_scenario_with_report(
+    language: impl Into<MergeLanguage>,
+    base: &str,
+    left: &str,
+    right: &str,
+    config: &ExecutionConfig,
+) -> Result<(ExecutionResult, MergeReport), ExecutionError> {
+    if let Some(result) = run_trivial_merge(base, left, right) {
+        return Ok((result, MergeReport::default()));
     }
 
-    let parser_configuration = ParserConfiguration::from(language);
+    let language = language.into();
+    let parser_configuration = get_parser_configuration(&language, config)?;
+    run_tool_on_merge_scenario_with_configuration(
+        &language,
+        &parser_configuration,
+        base,
+        left,
+        right,
+        config,
+    )
+}
 
-    let mut log_state = if print_chunks {
-        Some(LogState::default())
-    } else {
-        None
+/// Same as [`run_tool_on_merge_scenario_with_report`], parsing with a configuration built
+/// beforehand, such as one shared by several merges. The overrides in `config` are not applied
+/// to it.
+pub fn run_tool_on_merge_scenario_with_configuration(
+    language: &MergeLanguage,
+    parser_configuration: &ParserConfiguration,
+    base: &str,
+    left: &str,
+    right: &str,
+    config: &ExecutionConfig,
+) -> Result<(ExecutionResult, MergeReport), ExecutionError> {
+    if let Some(result) = run_trivial_merge(base, left, right) {
+        return Ok((result, MergeReport::default()));
+    }
+
+    match run_structured_merge(
+        language,
+        parser_configuration,
+        base,
+        left,
+        right,
+        config,
+        config.on_syntax_error,
+    ) {
+        Err(ExecutionError::InputWithSyntaxErrors(_))
+            if config.on_syntax_error == SyntaxErrorPolicy::Fallback =>
+        {
+            log::warn!("Falling back to textual merge because of syntax errors in the input");
+            Ok(textual_result(
+                merge::textual_merge(base, left, right),
+                FallbackReason::InputWithSyntaxErrors,
+                Timings::default(),
+                config,
+            ))
+        }
+        result => Ok(result?.into_result(config)),
+    }
+}
+
+/// Runs both structured and textual merge, and keeps the structured result unless it fails,
+/// has syntax errors in its inputs or output, or yields more conflicts than the textual one.
+pub fn run_hybrid_tool_on_merge_scenario(
+    language: impl Into<MergeLanguage>,
+    base: &str,
+    left: &str,
+    right: &str,
+    config: &ExecutionConfig,
+) -> HybridExecutionResult {
+    let language = language.into();
+    run_hybrid_merge(
+        &language,
+        get_parser_configuration(&language, config),
+        base,
+        left,
+        right,
+        config,
+    )
+}
+
+/// Same as [`run_hybrid_tool_on_merge_scenario`], parsing with a configuration built
+/// beforehand. The overrides in `config` are not applied to it.
+pub fn run_hybrid_tool_on_merge_scenario_with_configuration(
+    language: &MergeLanguage,
+    parser_configuration: &ParserConfiguration,
+    base: &str,
+    left: &str,
+    right: &str,
+    config: &ExecutionConfig,
+) -> HybridExecutionResult {
+    run_hybrid_merge(
+        language,
+        Ok(parser_configuration),
+        base,
+        left,
+        right,
+        config,
+    )
+}
+
+fn run_hybrid_merge<C: std::borrow::Borrow<ParserConfiguration>>(
+    language: &MergeLanguage,
+    parser_configuration: Result<C, ExecutionError>,
+    base: &str,
+    left: &str,
+    right: &str,
+    config: &ExecutionConfig,
+) -> HybridExecutionResult {
+    if let Some(result) = run_trivial_merge(base, left, right) {
+        return HybridExecutionResult {
+            result,
+            strategy: MergeStrategy::Structured,
+            report: MergeReport::default(),
+        };
+    }
+
+    let textual_merge = merge::textual_merge(base, left, right);
+    let textual_conflicts = textual_merge.conflict_count();
+
+    let structured_merge = parser_configuration.and_then(|parser_configuration| {
+        let parser_configuration = parser_configuration.borrow();
+        let output = run_structured_merge(
+            language,
+            parser_configuration,
+            base,
+            left,
+            right,
+            config,
+            SyntaxErrorPolicy::Fallback,
+        )?;
+        let output_parses = validate_merged_output_with_configuration(
+            parser_configuration,
+            &output.rendered,
+            &config.render_options,
+        )
+        .is_ok_and(|diagnostics| diagnostics.is_empty());
+        Ok((output, output_parses))
+    });
+
+    let (reason, timings) = match structured_merge {
+        Err(ExecutionError::InputWithSyntaxErrors(_)) => {
+            (FallbackReason::InputWithSyntaxErrors, Timings::default())
+        }
+        Err(error) => (
+            FallbackReason::StructuredMergeFailed(error.to_string()),
+            Timings::default(),
+        ),
+        Ok((output, false)) => (FallbackReason::MergedOutputDoesNotParse, output.timings),
+        Ok((output, _)) if output.merge_conflict_count() > textual_conflicts => (
+            FallbackReason::MoreConflictsThanTextualMerge {
+                structured: output.merge_conflict_count(),
+                textual: textual_conflicts,
+            },
+            output.timings,
+        ),
+        Ok((output, _)) => {
+            let (result, report) = output.into_result(config);
+            return HybridExecutionResult {
+                result,
+                strategy: MergeStrategy::Structured,
+                report,
+            };
+        }
     };
 
+    log::warn!("Falling back to textual merge because {reason}");
+    let (result, report) = textual_result(textual_merge, reason.clone(), timings, config);
+    HybridExecutionResult {
+        result,
+        strategy: MergeStrategy::Textual(reason),
+        report,
+    }
+}
+
+fn collect_syntax_diagnostics(
+    trees: [(Revision, &ParsedTree); 3],
+) -> Vec<(Revision, SyntaxDiagnostic)> {
+    trees
+        .into_iter()
+        .flat_map(|(revision, tree)| {
+            tree.diagnostics
+                .iter()
+                .map(move |diagnostic| (revision, diagnostic.clone()))
+        })
+        .collect()
+}
+
+/// The configuration of `language`, with the overrides in `config` applied
+pub fn get_parser_configuration(
+    language: &MergeLanguage,
+    config: &ExecutionConfig,
+) -> Result<ParserConfiguration, ExecutionError> {
+    let mut parser_configuration = language
+        .parser_configuration()
+        .map_err(ExecutionError::InvalidLanguageDefinition)?;
+    parser_configuration
+        .apply_overrides(&config.overrides)
+        .map_err(ExecutionError::InvalidOverrides)?;
+    Ok(parser_configuration)
+}
+
+fn run_structured_merge(
+    language: &MergeLanguage,
+    parser_configuration: &ParserConfiguration,
+    base: &str,
+    left: &str,
+    right: &str,
+    config: &ExecutionConfig,
+    on_syntax_error: SyntaxErrorPolicy,
+) -> Result<StructuredMergeOutput, ExecutionError> {
+    // Always kept, as it also records the conflicts found
+    let mut log_state = Some(LogState::default());
+
+    let parsing_start = Instant::now();
     let start = Instant::now();
     log::info!("Started parsing base file");
     let base_tree =
-        parsing::parse_string(base, &parser_configuration).map_err(ExecutionError::ParsingError)?;
+        parsing::parse_string(base, parser_configuration).map_err(ExecutionError::ParsingError)?;
     log::info!("Finished parsing base file in {:?}", start.elapsed());
 
     let start = Instant::now();
     log::info!("Started parsing left file");
     let left_tree =
-        parsing::parse_string(left, &parser_configuration).map_err(ExecutionError::ParsingError)?;
+        parsing::parse_string(left, parser_configuration).map_err(ExecutionError::ParsingError)?;
     log::info!("Finished parsing left file in {:?}", start.elapsed());
 
     let start = Instant::now();
     log::info!("Started parsing right file");
-    let right_tree = parsing::parse_string(right, &parser_configuration)
-        .map_err(ExecutionError::ParsingError)?;
+    let right_tree =
+        parsing::parse_string(right, parser_configuration).map_err(ExecutionError::ParsingError)?;
     log::info!("Finished parsing right file in {:?}", start.elapsed());
+    let parse_time = parsing_start.elapsed();
+
+    let diagnostics = collect_syntax_diagnostics([
+        (Revision::Base, &base_tree),
+        (Revision::Left, &left_tree),
+        (Revision::Right, &right_tree),
+    ]);
+    if !diagnostics.is_empty() {
+        for (revision, diagnostic) in &diagnostics {
+            log::warn!("{diagnostic} in {revision} file");
+        }
+        if on_syntax_error != SyntaxErrorPolicy::Continue {
+            return Err(ExecutionError::InputWithSyntaxErrors(diagnostics));
+        }
+    }
+    let trailing_white_space = merge_trailing_white_space(
+        base_tree.trailing_white_space,
+        left_tree.trailing_white_space,
+        right_tree.trailing_white_space,
+    );
+    let (base_tree, left_tree, right_tree) = (base_tree.root, left_tree.root, right_tree.root);
 
+    let matching_start = Instant::now();
     let start = Instant::now();
     log::info!("Started calculation of matchings between left and base");
     let matchings_left_base = matching::calculate_matchings(&left_tree, &base_tree);
@@ -110,6 +637,7 @@ pub fn run_tool_on_merge_scenario(
         "Finished calculation of matchings between left and right in {:?}",
         start.elapsed()
     );
+    let matching_time = matching_start.elapsed();
 
     let start = Instant::now();
     log::info!("Starting merge of the trees");
@@ -123,107 +651,156 @@ pub fn run_tool_on_merge_scenario(
         &mut log_state,
     )
     .map_err(ExecutionError::MergeError)?;
-    log::info!("Finished merge of the trees in {:?}", start.elapsed());
-
-    if let Some(final_log_state) = log_state {
-        let format_node_list_detailed = |nodes: &Vec<&model::CSTNode>| -> String {
-            if nodes.is_empty() {
-                return "-".to_string();
-            }
-            let first_node = nodes.first().unwrap();
-            let last_node = nodes.last().unwrap();
-
-            let start_line = first_node.start_position().row + 1;
-            let end_line = last_node.end_position().row + 1;
-
-            let range = if start_line == end_line {
-                format!("(L{start_line})")
-            } else {
-                format!("(L{start_line}-L{end_line})")
-            };
-
-            const MAX_NODES_TO_SHOW: usize = 3;
-            const MAX_CONTENT_LEN: usize = 25;
-            let descriptions: Vec<String> = nodes
-                .iter()
-                .map(|n| {
-                    let mut content = n.contents().replace(['\n', '\r'], " ").trim().to_string();
-                    if content.len() > MAX_CONTENT_LEN {
-                        content.truncate(MAX_CONTENT_LEN - 3);
-                        content.push_str("...");
-                    }
-                    format!("{}: '{}'", n.kind(), content)
-                })
-                .take(MAX_NODES_TO_SHOW)
-                .collect();
-            let mut summary = format!("[{}]", descriptions.join(", "));
-            if nodes.len() > MAX_NODES_TO_SHOW {
-                summary.push_str("...");
-            }
-
-            format!("{} nodes {} {}", nodes.len(), range, summary)
-        };
+    let merge_time = start.elapsed();
+    log::info!("Finished merge of the trees in {:?}", merge_time);
 
+    let final_log_state = log_state.unwrap_or_default();
+    if config.print_chunks {
         println!("\n--- CHUNK DEBUG LOG ---");
-        println!("===========================================================");
-        let mut chunk_counter = 0;
-        for chunk in final_log_state.log.iter() {
-            match chunk {
-                MergeChunk::Stable(data) => {
-                    chunk_counter += 1;
-                    println!("-- stable chunk #{chunk_counter} --");
-                    println!(
-                        "    Left (L):  {}",
-                        format_node_list_detailed(&data.left_nodes)
-                    );
-                    println!(
-                        "    Base (B):  {}",
-                        format_node_list_detailed(&data.base_nodes)
-                    );
-                    println!(
-                        "    Right (R): {}",
-                        format_node_list_detailed(&data.right_nodes)
-                    );
-                }
-                MergeChunk::Unstable(data) => {
-                    chunk_counter += 1;
-                    println!("-- unstable chunk #{chunk_counter} --");
-                    println!(
-                        "    Left (L):  {}",
-                        format_node_list_detailed(&data.left_nodes)
-                    );
-                    println!(
-                        "    Base (B):  {}",
-                        format_node_list_detailed(&data.base_nodes)
-                    );
-                    println!(
-                        "    Right (R): {}",
-                        format_node_list_detailed(&data.right_nodes)
-                    );
-                }
-                MergeChunk::UnorderedContextStart { node_kind } => {
-                    println!("\n---> START: Unordered Merge Context for '{node_kind}'");
-                }
-                MergeChunk::UnorderedContextEnd { node_kind } => {
-                    println!("\n---> END: Unordered Merge Context for '{node_kind}'");
-                }
-            }
-            println!("-----------------------------------------------------------");
+        match config.chunk_log_format {
+            ChunkLogFormat::Readable => print_readable_chunk_log(&final_log_state),
+            ChunkLogFormat::Text => print!(
+                "{}",
+                ChunkLog::new(&final_log_state, base, left, right).to_compact_text()
+            ),
         }
         println!("--- END CHUNK DEBUG LOG ---\n");
     }
+    let chunk_log = config
+        .record_chunk_log
+        .then(|| ChunkLog::new(&final_log_state, base, left, right));
+
+    let mut conflicts: Vec<ConflictReport> = final_log_state
+        .conflicts
+        .iter()
+        .map(ConflictReport::from)
+        .collect();
+    let result = match language.built_in() {
+        Some(language) => run_semantic_checks(
+            language,
+            result,
+            &SourceTrees {
+                base: &base_tree,
+                left: &left_tree,
+                right: &right_tree,
+            },
+            &mut conflicts,
+        ),
+        None => result,
+    };
 
     let start = Instant::now();
     log::info!("Started pretty-printing of final file");
-    let pretty_printed_tree = result.to_string();
+    let (mut pretty_printed_tree, conflict_locations) =
+        result.render_locating_conflicts(&config.render_options);
+    pretty_printed_tree.push_str(trailing_white_space);
     log::info!(
         "Finished pretty-printing of final file in {:?}",
         start.elapsed()
     );
 
-    match result.has_conflict() {
-        true => Ok(ExecutionResult::WithConflicts(pretty_printed_tree)),
-        false => Ok(ExecutionResult::WithoutConflicts(pretty_printed_tree)),
+    Ok(StructuredMergeOutput {
+        rendered: pretty_printed_tree,
+        conflict_locations,
+        conflicts,
+        timings: Timings::new(parse_time, matching_time, merge_time),
+        chunk_log,
+    })
+}
+
+/// The text ending the merged file, taken from the side that changed it, or from left if both did
+fn merge_trailing_white_space<'a>(base: &'a str, left: &'a str, right: &'a str) -> &'a str {
+    if left == base {
+        right
+    } else {
+        left
+    }
+}
+
+/// Prints the chunks of the merge with a preview of the nodes in each, for people to read
+fn print_readable_chunk_log(log_state: &LogState) {
+    let format_node_list_detailed = |nodes: &Vec<&model::CSTNode>| -> String {
+        if nodes.is_empty() {
+            return "-".to_string();
+        }
+        let first_node = nodes.first().unwrap();
+        let last_node = nodes.last().unwrap();
+
+        let start_line = first_node.start_position().row + 1;
+        let end_line = last_node.end_position().row + 1;
+
+        let range = if start_line == end_line {
+            format!("(L{start_line})")
+        } else {
+            format!("(L{start_line}-L{end_line})")
+        };
+
+        const MAX_NODES_TO_SHOW: usize = 3;
+        const MAX_CONTENT_LEN: usize = 25;
+        let descriptions: Vec<String> = nodes
+            .iter()
+            .map(|n| {
+                let mut content = n.contents().replace(['\n', '\r'], " ").trim().to_string();
+                if content.len() > MAX_CONTENT_LEN {
+                    content.truncate(MAX_CONTENT_LEN - 3);
+                    content.push_str("...");
+                }
+                format!("{}: '{}'", n.kind(), content)
+            })
+            .take(MAX_NODES_TO_SHOW)
+            .collect();
+        let mut summary = format!("[{}]", descriptions.join(", "));
+        if nodes.len() > MAX_NODES_TO_SHOW {
+            summary.push_str("...");
+        }
+
+        format!("{} nodes {} {}", nodes.len(), range, summary)
+    };
+
+    println!("===========================================================");
+    let mut chunk_counter = 0;
+    for chunk in log_state.log.iter() {
+        match chunk {
+            MergeChunk::Stable(data) => {
+                chunk_counter += 1;
+                println!("-- stable chunk #{chunk_counter} --");
+                println!(
+                    "    Left (L):  {}",
+                    format_node_list_detailed(&data.left_nodes)
+                );
+                println!(
+                    "    Base (B):  {}",
+                    format_node_list_detailed(&data.base_nodes)
+                );
+                println!(
+                    "    Right (R): {}",
+                    format_node_list_detailed(&data.right_nodes)
+                );
+            }
+            MergeChunk::Unstable(data) => {
+                chunk_counter += 1;
+                println!("-- unstable chunk #{chunk_counter} --");
+                println!(
+                    "    Left (L):  {}",
+                    format_node_list_detailed(&data.left_nodes)
+                );
+                println!(
+                    "    Base (B):  {}",
+                    format_node_list_detailed(&data.base_nodes)
+                );
+                println!(
+                    "    Right (R): {}",
+                    format_node_list_detailed(&data.right_nodes)
+                );
+            }
+            MergeChunk::UnorderedContextStart { node_kind } => {
+                println!("\n---> START: Unordered Merge Context for '{node_kind}'");
+            }
+            MergeChunk::UnorderedContextEnd { node_kind } => {
+                println!("\n---> END: Unordered Merge Context for '{node_kind}'");
+            }
+        }
+        println!("-----------------------------------------------------------");
     }
 }
 
@@ -235,12 +812,14 @@ pub fn run_diff_on_files(
     let parser_configuration = ParserConfiguration::from(language);
 
     log::info!("Started parsing left file");
-    let left_tree_root =
-        parsing::parse_string(left, &parser_configuration).map_err(ExecutionError::ParsingError)?;
+    let left_tree_root = parsing::parse_string(left, &parser_configuration)
+        .map_err(ExecutionError::ParsingError)?
+        .root;
     log::info!("Finished parsing left file");
     log::info!("Started parsing right file");
     let right_tree_root = parsing::parse_string(right, &parser_configuration)
-        .map_err(ExecutionError::ParsingError)?;
+        .map_err(ExecutionError::ParsingError)?
+        .root;
     log::info!("Finished parsing right file");
 
     log::info!("Left tree size: {}", left_tree_root.get_tree_size());
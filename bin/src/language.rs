@@ -1,11 +1,9 @@
+use parsing::LanguageDefinition;
+
+use bin::MergeLanguage;
+
 pub fn get_language_from_name(name: &str) -> Result<model::Language, String> {
-    match name {
-        "java" => Ok(model::Language::Java),
-        "csharp" | "cs" => Ok(model::Language::CSharp),
-        "javascript" | "js" => Ok(model::Language::JavaScript),
-        "go" => Ok(model::Language::Go),
-        _ => Err(format!("Invalid language provided: {name}")),
-    }
+    name.parse()
 }
 
 pub fn get_language_by_file_path(file_path: &std::path::Path) -> Result<model::Language, String> {
@@ -17,6 +15,19 @@ pub fn get_language_by_file_path(file_path: &std::path::Path) -> Result<model::L
             "cs" => Some(model::Language::CSharp),
             "js" => Some(model::Language::JavaScript),
             "go" => Some(model::Language::Go),
+            "ts" | "mts" | "cts" => Some(model::Language::TypeScript),
+            "tsx" => Some(model::Language::Tsx),
+            "py" | "pyi" => Some(model::Language::Python),
+            "rs" => Some(model::Language::Rust),
+            "kt" | "kts" => Some(model::Language::Kotlin),
+            "c" => Some(model::Language::C),
+            "json" => Some(model::Language::Json),
+            "yaml" | "yml" => Some(model::Language::Yaml),
+            "toml" => Some(model::Language::Toml),
+            "xml" | "pom" => Some(model::Language::Xml),
+            "proto" => Some(model::Language::Proto),
+            // Headers are parsed as C++, whose grammar also takes C headers
+            "cpp" | "cc" | "cxx" | "c++" | "h" | "hpp" | "hh" | "hxx" => Some(model::Language::Cpp),
             _ => None,
         })
         .ok_or(format!(
@@ -25,6 +36,29 @@ pub fn get_language_by_file_path(file_path: &std::path::Path) -> Result<model::L
         ))
 }
 
+/// The language of `file_path`: the definition at `definition_path` when it applies to the file,
+/// or else a built-in language
+pub fn resolve_language(
+    name: Option<&str>,
+    definition_path: Option<&std::path::Path>,
+    file_path: &std::path::Path,
+) -> Result<MergeLanguage, String> {
+    if let Some(definition_path) = definition_path {
+        let definition =
+            LanguageDefinition::from_file(definition_path).map_err(|error| error.to_string())?;
+        if definition.applies_to(file_path) {
+            log::info!("Using the {} language definition", definition.name);
+            return Ok(MergeLanguage::from(definition));
+        }
+    }
+
+    match name {
+        Some(name) => get_language_from_name(name),
+        None => get_language_by_file_path(file_path),
+    }
+    .map(MergeLanguage::BuiltIn)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::language::get_language_by_file_path;
@@ -43,4 +77,18 @@ mod tests {
             model::Language::Java
         )
     }
+
+    #[test]
+    fn it_tells_typescript_and_tsx_files_apart() {
+        let typescript_file = std::path::PathBuf::from("/path/for/typescript/file/user.ts");
+        let tsx_file = std::path::PathBuf::from("/path/for/typescript/file/Button.tsx");
+        assert_eq!(
+            get_language_by_file_path(&typescript_file).unwrap(),
+            model::Language::TypeScript
+        );
+        assert_eq!(
+            get_language_by_file_path(&tsx_file).unwrap(),
+            model::Language::Tsx
+        );
+    }
 }
@@ -1,5 +1,23 @@
 mod cli_exit_codes;
 mod control;
+mod report;
+mod semantic_checks;
+mod validation;
 
 pub use cli_exit_codes::*;
-pub use control::{run_diff_on_files, run_tool_on_merge_scenario};
+pub use control::{
+    get_parser_configuration, run_diff_on_files, run_hybrid_tool_on_merge_scenario,
+    run_hybrid_tool_on_merge_scenario_with_configuration, run_textual_merge_on_scenario,
+    run_tool_on_merge_scenario, run_tool_on_merge_scenario_with_configuration,
+    run_tool_on_merge_scenario_with_report, ChunkLogFormat, ExecutionConfig, ExecutionError,
+    ExecutionResult, FallbackReason, HybridExecutionResult, MergeLanguage, MergeStrategy, Revision,
+    SyntaxErrorPolicy,
+};
+pub use parsing::{
+    ConfigurationOverrides, ConfigurationOverridesError, LanguageDefinition,
+    LanguageDefinitionError,
+};
+pub use report::{
+    ConflictMerge, ConflictReport, LineRange, MergeReport, ReportedStrategy, Timings,
+};
+pub use validation::{validate_merged_output, validate_merged_output_with_configuration};
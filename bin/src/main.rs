@@ -1,10 +1,14 @@
 use clap::Parser;
-use cli_args::{CliArgs, CliSubCommands, DiffCliArgs, MergeCliArgs};
+use repository_config::RepositoryConfig;
+
+use cli_args::{
+    CliArgs, CliSubCommands, DiffCliArgs, GitDriverCliArgs, MergeCliArgs, MergeFileCliArgs,
+    MergeOptions,
+};
 
 mod cli_args;
-mod cli_exit_codes;
-mod control;
 mod language;
+mod repository_config;
 
 fn main() {
     let args = CliArgs::parse();
@@ -16,64 +20,403 @@ fn main() {
     match args.command {
         CliSubCommands::Diff(args) => run_diff(args),
         CliSubCommands::Merge(args) => run_merge(args),
+        CliSubCommands::GitDriver(args) => run_git_driver(args),
+        CliSubCommands::MergeFile(args) => run_merge_file(args),
     }
 }
 
 fn run_merge(args: MergeCliArgs) {
     let base = std::fs::read_to_string(&args.base_path).unwrap_or_else(|error| {
         log::error!("Error while reading base file: {}", error);
-        std::process::exit(cli_exit_codes::READING_FILE_ERROR)
+        std::process::exit(bin::READING_FILE_ERROR)
     });
     let left = std::fs::read_to_string(&args.left_path).unwrap_or_else(|error| {
         log::error!("Error while reading left file: {}", error);
-        std::process::exit(cli_exit_codes::READING_FILE_ERROR)
+        std::process::exit(bin::READING_FILE_ERROR)
     });
     let right = std::fs::read_to_string(&args.right_path).unwrap_or_else(|error| {
         log::error!("Error while reading right file: {}", error);
-        std::process::exit(cli_exit_codes::READING_FILE_ERROR)
+        std::process::exit(bin::READING_FILE_ERROR)
     });
 
-    let language = match args.language {
-        Some(language) => language::get_language_from_name(&language),
-        None => language::get_language_by_file_path(&args.base_path),
+    let repository_config = load_repository_config(&args.merge_path);
+    let language = resolve_merge_language(&args.options, &args.merge_path, &repository_config);
+
+    let config = bin::ExecutionConfig {
+        print_chunks: args.print_chunks,
+        chunk_log_format: args.chunk_log_format,
+        record_chunk_log: args.chunk_log.is_some(),
+        render_options: merge::RenderOptions {
+            left_label: repository_config.labels.left.clone(),
+            base_label: repository_config.labels.base.clone(),
+            right_label: repository_config.labels.right.clone(),
+            style: conflict_style(args.conflict_style, &repository_config),
+            ..Default::default()
+        },
+        on_syntax_error: args.options.on_syntax_error,
+        ..Default::default()
+    };
+
+    let (result, report) = run_tool(
+        language.as_ref(),
+        &base,
+        &left,
+        &right,
+        &config,
+        args.options.hybrid,
+    );
+
+    if let Some(language) = language.as_ref().filter(|_| args.options.validate) {
+        report_merged_output_validation(
+            &language.parser_configuration,
+            &result.to_string(),
+            &config,
+        );
     }
-    .unwrap_or_else(|error| {
-        log::error!("Error while retrieving language configuration: {}", error);
-        std::process::exit(cli_exit_codes::INVALID_LANGUAGE_ERROR)
-    });
 
-    let result =
-        control::run_tool_on_merge_scenario(language, &base, &left, &right, args.print_chunks)
-            .unwrap_or_else(|error| {
-                log::error!("Error while running tool: {}", error);
-                std::process::exit(cli_exit_codes::INTERNAL_EXECUTION_ERROR)
-            });
+    if let Some(chunk_log_path) = &args.chunk_log {
+        write_chunk_log(chunk_log_path, report.chunk_log.as_ref());
+    }
+
+    if let Some(report_path) = &args.options.report {
+        write_report(report_path, &report);
+    }
 
     std::fs::write(args.merge_path, result.to_string()).unwrap_or_else(|error| {
         log::error!("Error while writing output file: {}", error);
-        std::process::exit(cli_exit_codes::WRITING_FILE_ERROR)
+        std::process::exit(bin::WRITING_FILE_ERROR)
     });
 
     match result {
-        control::ExecutionResult::WithConflicts(_) => {
+        bin::ExecutionResult::WithConflicts(_) => {
             log::info!("Execution finished with conflicts");
-            std::process::exit(cli_exit_codes::SUCCESS_WITH_CONFLICTS)
+            std::process::exit(bin::SUCCESS_WITH_CONFLICTS)
+        }
+        bin::ExecutionResult::WithoutConflicts(_) => {
+            log::info!("Execution finished without conflicts");
+            std::process::exit(bin::SUCCESS_WITHOUT_CONFLICTS)
+        }
+    }
+}
+
+fn run_git_driver(args: GitDriverCliArgs) {
+    let base = std::fs::read_to_string(&args.base_path).unwrap_or_else(|error| {
+        log::error!("Error while reading ancestor file: {}", error);
+        std::process::exit(bin::READING_FILE_ERROR)
+    });
+    let left = std::fs::read_to_string(&args.left_path).unwrap_or_else(|error| {
+        log::error!("Error while reading current file: {}", error);
+        std::process::exit(bin::READING_FILE_ERROR)
+    });
+    let right = std::fs::read_to_string(&args.right_path).unwrap_or_else(|error| {
+        log::error!("Error while reading other file: {}", error);
+        std::process::exit(bin::READING_FILE_ERROR)
+    });
+
+    let repository_config = load_repository_config(&args.path_name);
+    let language = resolve_merge_language(&args.options, &args.path_name, &repository_config);
+
+    let config = bin::ExecutionConfig {
+        render_options: merge::RenderOptions {
+            left_label: repository_config.labels.left.clone(),
+            base_label: repository_config.labels.base.clone(),
+            right_label: repository_config.labels.right.clone(),
+            marker_size: args.marker_size,
+            style: conflict_style(args.conflict_style, &repository_config),
+            ..Default::default()
+        },
+        on_syntax_error: args.options.on_syntax_error,
+        ..Default::default()
+    };
+
+    let (result, report) = run_tool(
+        language.as_ref(),
+        &base,
+        &left,
+        &right,
+        &config,
+        args.options.hybrid,
+    );
+
+    if let Some(language) = language.as_ref().filter(|_| args.options.validate) {
+        report_merged_output_validation(
+            &language.parser_configuration,
+            &result.to_string(),
+            &config,
+        );
+    }
+
+    if let Some(report_path) = &args.options.report {
+        write_report(report_path, &report);
+    }
+
+    std::fs::write(&args.left_path, result.to_string()).unwrap_or_else(|error| {
+        log::error!("Error while writing merge result: {}", error);
+        std::process::exit(bin::WRITING_FILE_ERROR)
+    });
+
+    match result {
+        bin::ExecutionResult::WithConflicts(_) => {
+            log::info!(
+                "Merge of {} finished with conflicts",
+                args.path_name.display()
+            );
+            std::process::exit(bin::SUCCESS_WITH_CONFLICTS)
+        }
+        bin::ExecutionResult::WithoutConflicts(_) => {
+            log::info!(
+                "Merge of {} finished without conflicts",
+                args.path_name.display()
+            );
+            std::process::exit(bin::SUCCESS_WITHOUT_CONFLICTS)
+        }
+    }
+}
+
+fn run_merge_file(args: MergeFileCliArgs) {
+    let base = std::fs::read_to_string(&args.base_path).unwrap_or_else(|error| {
+        log::error!("Error while reading base file: {}", error);
+        std::process::exit(bin::READING_FILE_ERROR)
+    });
+    let left = std::fs::read_to_string(&args.left_path).unwrap_or_else(|error| {
+        log::error!("Error while reading current file: {}", error);
+        std::process::exit(bin::READING_FILE_ERROR)
+    });
+    let right = std::fs::read_to_string(&args.right_path).unwrap_or_else(|error| {
+        log::error!("Error while reading other file: {}", error);
+        std::process::exit(bin::READING_FILE_ERROR)
+    });
+
+    let repository_config = load_repository_config(&args.left_path);
+    let language = resolve_merge_language(&args.options, &args.left_path, &repository_config);
+
+    let config = bin::ExecutionConfig {
+        render_options: args.render_options(&repository_config),
+        on_syntax_error: args.options.on_syntax_error,
+        ..Default::default()
+    };
+
+    let (result, report) = run_tool(
+        language.as_ref(),
+        &base,
+        &left,
+        &right,
+        &config,
+        args.options.hybrid,
+    );
+
+    if let Some(language) = language.as_ref().filter(|_| args.options.validate) {
+        report_merged_output_validation(
+            &language.parser_configuration,
+            &result.to_string(),
+            &config,
+        );
+    }
+
+    if let Some(report_path) = &args.options.report {
+        write_report(report_path, &report);
+    }
+
+    if args.stdout {
+        print!("{result}");
+    } else {
+        std::fs::write(&args.left_path, result.to_string()).unwrap_or_else(|error| {
+            log::error!("Error while writing merge result: {}", error);
+            std::process::exit(bin::WRITING_FILE_ERROR)
+        });
+    }
+
+    match result {
+        bin::ExecutionResult::WithConflicts(_) => {
+            if !args.quiet {
+                log::warn!(
+                    "Merge of {} finished with conflicts",
+                    args.left_path.display()
+                );
+            }
+            std::process::exit(merge_file_exit_code(report.conflict_count))
         }
-        control::ExecutionResult::WithoutConflicts(_) => {
+        bin::ExecutionResult::WithoutConflicts(_) => {
             log::info!("Execution finished without conflicts");
-            std::process::exit(cli_exit_codes::SUCCESS_WITHOUT_CONFLICTS)
+            std::process::exit(bin::SUCCESS_WITHOUT_CONFLICTS)
+        }
+    }
+}
+
+/// The number of conflicts, as `git merge-file` returns it
+fn merge_file_exit_code(conflict_count: usize) -> i32 {
+    i32::try_from(conflict_count)
+        .unwrap_or(i32::MAX)
+        .clamp(bin::SUCCESS_WITH_CONFLICTS, bin::MAX_CONFLICTS_EXIT_CODE)
+}
+
+/// The configuration of the repository `file_path` is in, looked up from the directory of the
+/// file
+fn load_repository_config(file_path: &std::path::Path) -> RepositoryConfig {
+    let directory = std::env::current_dir()
+        .unwrap_or_default()
+        .join(file_path.parent().unwrap_or(std::path::Path::new("")));
+    RepositoryConfig::discover(&directory)
+        .unwrap_or_else(|error| {
+            log::error!("Error while loading repository configuration: {}", error);
+            std::process::exit(bin::INVALID_CONFIGURATION_ERROR)
+        })
+        .unwrap_or_default()
+}
+
+fn conflict_style(
+    style: Option<merge::ConflictStyle>,
+    repository_config: &RepositoryConfig,
+) -> merge::ConflictStyle {
+    style
+        .or(repository_config.conflict_style)
+        .unwrap_or_default()
+}
+
+/// A language along with its parser configuration, which is built once per run
+struct ResolvedLanguage {
+    language: bin::MergeLanguage,
+    parser_configuration: parsing::ParserConfiguration,
+}
+
+/// The language `file_path` is merged with, or none if the repository excludes it from
+/// structured merge
+fn resolve_merge_language(
+    options: &MergeOptions,
+    file_path: &std::path::Path,
+    repository_config: &RepositoryConfig,
+) -> Option<ResolvedLanguage> {
+    if repository_config.is_excluded(file_path) {
+        log::info!(
+            "Merging {} textually, as it is excluded from structured merge",
+            file_path.display()
+        );
+        return None;
+    }
+
+    let language = language::resolve_language(
+        options.language.as_deref(),
+        options.language_definition.as_deref(),
+        file_path,
+    )
+    .unwrap_or_else(|error| {
+        log::error!("Error while retrieving language configuration: {}", error);
+        std::process::exit(bin::INVALID_LANGUAGE_ERROR)
+    });
+
+    let mut parser_configuration = language.parser_configuration().unwrap_or_else(|error| {
+        log::error!("Error while retrieving language configuration: {}", error);
+        std::process::exit(bin::INVALID_LANGUAGE_ERROR)
+    });
+    parser_configuration
+        .apply_overrides(&repository_config.overrides_for(&language))
+        .unwrap_or_else(|error| {
+            log::error!("Error while loading repository configuration: {}", error);
+            std::process::exit(bin::INVALID_CONFIGURATION_ERROR)
+        });
+
+    Some(ResolvedLanguage {
+        language,
+        parser_configuration,
+    })
+}
+
+fn run_tool(
+    language: Option<&ResolvedLanguage>,
+    base: &str,
+    left: &str,
+    right: &str,
+    config: &bin::ExecutionConfig,
+    hybrid: bool,
+) -> (bin::ExecutionResult, bin::MergeReport) {
+    let Some(language) = language else {
+        return bin::run_textual_merge_on_scenario(base, left, right, config);
+    };
+
+    if hybrid {
+        let result = bin::run_hybrid_tool_on_merge_scenario_with_configuration(
+            &language.language,
+            &language.parser_configuration,
+            base,
+            left,
+            right,
+            config,
+        );
+        log::info!("Merged using the {} strategy", result.strategy);
+        return (result.result, result.report);
+    }
+
+    bin::run_tool_on_merge_scenario_with_configuration(
+        &language.language,
+        &language.parser_configuration,
+        base,
+        left,
+        right,
+        config,
+    )
+    .unwrap_or_else(|error| {
+        log::error!("Error while running tool: {}", error);
+        std::process::exit(bin::INTERNAL_EXECUTION_ERROR)
+    })
+}
+
+fn write_report(path: &std::path::Path, report: &bin::MergeReport) {
+    let json = report.to_json().unwrap_or_else(|error| {
+        log::error!("Error while serializing merge report: {}", error);
+        std::process::exit(bin::INTERNAL_EXECUTION_ERROR)
+    });
+    std::fs::write(path, json).unwrap_or_else(|error| {
+        log::error!("Error while writing merge report: {}", error);
+        std::process::exit(bin::WRITING_FILE_ERROR)
+    });
+}
+
+fn write_chunk_log(path: &std::path::Path, chunk_log: Option<&merge::ChunkLog>) {
+    // Merges that never reach the structured step have no chunks
+    let json = chunk_log
+        .cloned()
+        .unwrap_or_default()
+        .to_json()
+        .unwrap_or_else(|error| {
+            log::error!("Error while serializing chunk log: {}", error);
+            std::process::exit(bin::INTERNAL_EXECUTION_ERROR)
+        });
+    std::fs::write(path, json).unwrap_or_else(|error| {
+        log::error!("Error while writing chunk log: {}", error);
+        std::process::exit(bin::WRITING_FILE_ERROR)
+    });
+}
+
+fn report_merged_output_validation(
+    parser_configuration: &parsing::ParserConfiguration,
+    merged: &str,
+    config: &bin::ExecutionConfig,
+) {
+    match bin::validate_merged_output_with_configuration(
+        parser_configuration,
+        merged,
+        &config.render_options,
+    ) {
+        Ok(diagnostics) if diagnostics.is_empty() => {
+            log::info!("Merged output has no syntax errors")
+        }
+        Ok(diagnostics) => {
+            for diagnostic in diagnostics {
+                log::warn!("{diagnostic} in merged output");
+            }
         }
+        Err(error) => log::error!("Error while validating merged output: {}", error),
     }
 }
 
 fn run_diff(args: DiffCliArgs) {
     let left = std::fs::read_to_string(&args.left_path).unwrap_or_else(|error| {
         log::error!("Error while reading left file: {}", error);
-        std::process::exit(cli_exit_codes::READING_FILE_ERROR)
+        std::process::exit(bin::READING_FILE_ERROR)
     });
     let right = std::fs::read_to_string(&args.right_path).unwrap_or_else(|error| {
         log::error!("Error while reading right file: {}", error);
-        std::process::exit(cli_exit_codes::READING_FILE_ERROR)
+        std::process::exit(bin::READING_FILE_ERROR)
     });
 
     let language = match args.language {
@@ -82,23 +425,23 @@ fn run_diff(args: DiffCliArgs) {
     }
     .unwrap_or_else(|error| {
         log::error!("Error while retrieving language configuration: {}", error);
-        std::process::exit(cli_exit_codes::INVALID_LANGUAGE_ERROR)
+        std::process::exit(bin::INVALID_LANGUAGE_ERROR)
     });
 
-    let result = control::run_diff_on_files(language, &left, &right).unwrap_or_else(|error| {
+    let result = bin::run_diff_on_files(language, &left, &right).unwrap_or_else(|error| {
         log::error!("Error while running tool: {}", error);
-        std::process::exit(cli_exit_codes::INTERNAL_EXECUTION_ERROR)
+        std::process::exit(bin::INTERNAL_EXECUTION_ERROR)
     });
 
     log::info!("{:?}", result);
     match result.is_perfect_match {
         true => {
             log::info!("Both files are equivalent");
-            std::process::exit(cli_exit_codes::SUCCESS_FILES_FULLY_MATCH)
+            std::process::exit(bin::SUCCESS_FILES_FULLY_MATCH)
         }
         false => {
             log::info!("Both files are different");
-            std::process::exit(cli_exit_codes::SUCCESS_FILES_DO_NOT_FULLY_MATCH)
+            std::process::exit(bin::SUCCESS_FILES_DO_NOT_FULLY_MATCH)
         }
     }
 }
@@ -0,0 +1,191 @@
+use std::{collections::HashSet, time::Duration};
+
+use merge::{
+    log_structures::{ConflictRecord, ConflictSource},
+    ChunkLog, ConflictLocation,
+};
+use serde::Serialize;
+
+use crate::control::MergeStrategy;
+
+/// A summary of a merge meant for tools, such as bots annotating pull requests
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MergeReport {
+    pub strategy: ReportedStrategy,
+    pub fallback_reason: Option<String>,
+    pub conflict_count: usize,
+    pub conflicts: Vec<ConflictReport>,
+    pub timings: Timings,
+    /// The chunks of the structured merge, if asked for through `ExecutionConfig`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunk_log: Option<ChunkLog>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportedStrategy {
+    #[default]
+    Structured,
+    Textual,
+}
+
+/// How the conflicting content was merged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictMerge {
+    Ordered,
+    Unordered,
+    /// Line based merge of terminals changed in both sides
+    Terminal,
+    DifferentKinds,
+    LeadingTrivia,
+    DuplicateIdentifier,
+    /// Protocol Buffers fields added by each side with the same number
+    FieldNumberClash,
+    /// Line based merge of the whole file
+    Textual,
+}
+
+impl From<ConflictSource> for ConflictMerge {
+    fn from(source: ConflictSource) -> Self {
+        match source {
+            ConflictSource::OrderedMerge => ConflictMerge::Ordered,
+            ConflictSource::UnorderedMerge => ConflictMerge::Unordered,
+            ConflictSource::TerminalMerge => ConflictMerge::Terminal,
+            ConflictSource::DifferentKinds => ConflictMerge::DifferentKinds,
+            ConflictSource::LeadingTrivia => ConflictMerge::LeadingTrivia,
+            ConflictSource::DuplicateIdentifier => ConflictMerge::DuplicateIdentifier,
+        }
+    }
+}
+
+/// An inclusive range of 1-based line numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LineRange {
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+impl LineRange {
+    fn from_rows(start_row: usize, end_row: usize) -> Self {
+        LineRange {
+            start_line: start_row + 1,
+            end_line: end_row + 1,
+        }
+    }
+
+    pub(crate) fn of_node(node: &model::CSTNode) -> Self {
+        LineRange::from_rows(node.start_position().row, node.end_position().row)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConflictReport {
+    /// The kind of the conflicting node, absent for textual merges
+    pub node_kind: Option<String>,
+    pub merge: ConflictMerge,
+    pub left: Option<LineRange>,
+    pub base: Option<LineRange>,
+    pub right: Option<LineRange>,
+    /// Where the conflict markers are in the merged file
+    pub output: Option<LineRange>,
+}
+
+impl From<&ConflictRecord<'_>> for ConflictReport {
+    fn from(record: &ConflictRecord) -> Self {
+        ConflictReport {
+            node_kind: record.node_kind().map(String::from),
+            merge: record.source.into(),
+            left: record.left.map(LineRange::of_node),
+            base: record.base.map(LineRange::of_node),
+            right: record.right.map(LineRange::of_node),
+            output: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct Timings {
+    pub parse_ms: f64,
+    pub matching_ms: f64,
+    pub merge_ms: f64,
+}
+
+impl Timings {
+    pub(crate) fn new(parse: Duration, matching: Duration, merge: Duration) -> Self {
+        Timings {
+            parse_ms: parse.as_secs_f64() * 1000.0,
+            matching_ms: matching.as_secs_f64() * 1000.0,
+            merge_ms: merge.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+impl MergeReport {
+    pub(crate) fn new(
+        strategy: &MergeStrategy,
+        conflicts: Vec<ConflictReport>,
+        timings: Timings,
+    ) -> Self {
+        let (strategy, fallback_reason) = match strategy {
+            MergeStrategy::Structured => (ReportedStrategy::Structured, None),
+            MergeStrategy::Textual(reason) => (ReportedStrategy::Textual, Some(reason.to_string())),
+        };
+        MergeReport {
+            strategy,
+            fallback_reason,
+            conflict_count: conflicts.len(),
+            conflicts,
+            timings,
+            chunk_log: None,
+        }
+    }
+
+    /// Conflicts of a line based merge of the whole file, which carry no node information
+    pub(crate) fn textual_conflicts(count: usize) -> Vec<ConflictReport> {
+        (0..count)
+            .map(|_| ConflictReport {
+                node_kind: None,
+                merge: ConflictMerge::Textual,
+                left: None,
+                base: None,
+                right: None,
+                output: None,
+            })
+            .collect()
+    }
+
+    /// Fills in where each conflict ended up in the merged file and puts the conflicts in output
+    /// order. Each conflict is found by the index of its report that it keeps, and the ones that
+    /// keep none, such as those of textual merges, are taken in order.
+    pub(crate) fn locate_conflicts_in_output(&mut self, locations: &[ConflictLocation]) {
+        let mut unlocated: Vec<Option<ConflictReport>> = std::mem::take(&mut self.conflicts)
+            .into_iter()
+            .map(Some)
+            .collect();
+        let recorded: HashSet<usize> = locations
+            .iter()
+            .filter_map(|location| location.record)
+            .collect();
+        let mut not_recorded = (0..unlocated.len()).filter(|index| !recorded.contains(index));
+
+        for location in locations {
+            let Some(mut conflict) = location
+                .record
+                .or_else(|| not_recorded.next())
+                .and_then(|index| unlocated.get_mut(index)?.take())
+            else {
+                continue;
+            };
+            conflict.output = location
+                .rows
+                .map(|(start_row, end_row)| LineRange::from_rows(start_row, end_row));
+            self.conflicts.push(conflict);
+        }
+        self.conflicts.extend(unlocated.into_iter().flatten());
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use parsing::ConfigurationOverrides;
+use serde::Deserialize;
+
+use bin::MergeLanguage;
+
+pub const REPOSITORY_CONFIG_FILE_NAME: &str = ".last-merge.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RepositoryConfigFile {
+    conflict_style: Option<String>,
+    #[serde(default)]
+    labels: ConflictLabels,
+    #[serde(default)]
+    exclude: Vec<String>,
+    #[serde(default)]
+    languages: HashMap<String, ConfigurationOverrides>,
+}
+
+/// Labels written after the conflict markers
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConflictLabels {
+    pub left: Option<String>,
+    pub base: Option<String>,
+    pub right: Option<String>,
+}
+
+/// Settings a repository keeps in a `.last-merge.toml` file, overriding the built-in ones
+#[derive(Debug, Default)]
+pub struct RepositoryConfig {
+    /// The directory holding the file, to which excluded paths are relative
+    root: PathBuf,
+    pub conflict_style: Option<merge::ConflictStyle>,
+    pub labels: ConflictLabels,
+    excluded: GlobSet,
+    languages: HashMap<String, ConfigurationOverrides>,
+}
+
+impl RepositoryConfig {
+    /// Looks for the configuration file in `directory` and its parents, up to the root of the
+    /// repository it is in
+    pub fn discover(directory: &Path) -> Result<Option<Self>, String> {
+        for directory in directory.ancestors() {
+            let path = directory.join(REPOSITORY_CONFIG_FILE_NAME);
+            if path.is_file() {
+                return Self::load(&path).map(Some);
+            }
+            if directory.join(".git").exists() {
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let src = std::fs::read_to_string(path)
+            .map_err(|error| format!("Could not read {}: {error}", path.display()))?;
+        let file: RepositoryConfigFile = toml::from_str(&src)
+            .map_err(|error| format!("Invalid configuration in {}: {error}", path.display()))?;
+
+        let mut excluded = GlobSetBuilder::new();
+        for pattern in &file.exclude {
+            let glob = GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .map_err(|error| format!("Invalid excluded path {pattern}: {error}"))?;
+            excluded.add(glob);
+        }
+
+        Ok(Self {
+            root: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+            conflict_style: file.conflict_style.as_deref().map(str::parse).transpose()?,
+            labels: file.labels,
+            excluded: excluded.build().map_err(|error| error.to_string())?,
+            languages: file.languages,
+        })
+    }
+
+    /// Whether `file_path`, relative to the current directory, matches one of the paths excluded
+    /// from structured merge
+    pub fn is_excluded(&self, file_path: &Path) -> bool {
+        let absolute_path = std::env::current_dir()
+            .map(|directory| directory.join(file_path))
+            .unwrap_or_else(|_| file_path.to_path_buf());
+        let relative_path = absolute_path.strip_prefix(&self.root).unwrap_or(file_path);
+        self.excluded.is_match(relative_path)
+    }
+
+    /// The overrides for `language`, kept under its name or the name of its definition
+    pub fn overrides_for(&self, language: &MergeLanguage) -> ConfigurationOverrides {
+        self.languages
+            .iter()
+            .find(|(name, _)| match language {
+                MergeLanguage::BuiltIn(language) => name.parse().ok() == Some(*language),
+                MergeLanguage::Defined(definition) => **name == definition.name,
+            })
+            .map(|(_, overrides)| overrides.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_config(name: &str, src: &str) -> Result<RepositoryConfig, String> {
+        let directory = std::env::temp_dir().join(format!("last-merge-{name}"));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join(REPOSITORY_CONFIG_FILE_NAME);
+        std::fs::write(&path, src).unwrap();
+        RepositoryConfig::load(&path)
+    }
+
+    #[test]
+    fn excluded_paths_are_matched_from_the_directory_of_the_configuration() {
+        let config =
+            load_config("excluded", "exclude = [\"src/generated/**\", \"*.pb.go\"]").unwrap();
+
+        assert!(config.is_excluded(&config.root.join("src/generated/Parser.java")));
+        assert!(config.is_excluded(&config.root.join("api.pb.go")));
+        assert!(!config.is_excluded(&config.root.join("cmd/api.pb.go")));
+        assert!(!config.is_excluded(&config.root.join("src/Main.java")));
+    }
+
+    #[test]
+    fn overrides_are_found_by_any_name_of_the_language() {
+        let config = load_config(
+            "overrides",
+            "[languages.cs]\nkinds_with_unordered_children = [\"argument_list\"]\n",
+        )
+        .unwrap();
+
+        let overrides = config.overrides_for(&MergeLanguage::BuiltIn(model::Language::CSharp));
+        assert_eq!(
+            overrides.kinds_with_unordered_children,
+            vec!["argument_list"]
+        );
+        let overrides = config.overrides_for(&MergeLanguage::BuiltIn(model::Language::Java));
+        assert!(overrides.kinds_with_unordered_children.is_empty());
+    }
+
+    #[test]
+    fn an_invalid_conflict_style_is_rejected() {
+        assert!(load_config("invalid_style", "conflict_style = \"zdiff3\"").is_err());
+    }
+}
@@ -0,0 +1,364 @@
+use std::collections::{HashMap, HashSet};
+
+use merge::MergedCSTNode;
+use model::CSTNode;
+
+use crate::report::{ConflictMerge, ConflictReport, LineRange};
+
+const PROTO_BODY_KINDS: [&str; 2] = ["message_body", "enum_body"];
+const PROTO_FIELD_KINDS: [&str; 4] = ["field", "map_field", "enum_field", "oneof_field"];
+/// Kinds naming the messages and enums fields are declared in
+const PROTO_SCOPE_NAME_KINDS: [&str; 2] = ["message_name", "enum_name"];
+
+/// The trees a merged tree was built from
+pub(crate) struct SourceTrees<'t> {
+    pub base: &'t CSTNode<'t>,
+    pub left: &'t CSTNode<'t>,
+    pub right: &'t CSTNode<'t>,
+}
+
+/// Looks for mistakes in a merged tree that no structured merge step can see, as each side is
+/// fine on its own while their combination is not. Every mistake is turned into a conflict whose
+/// report is added to `conflicts`, and which keeps the index of that report.
+pub(crate) fn run_semantic_checks<'a>(
+    language: model::Language,
+    merged: MergedCSTNode<'a>,
+    sources: &SourceTrees,
+    conflicts: &mut Vec<ConflictReport>,
+) -> MergedCSTNode<'a> {
+    match language {
+        model::Language::Proto => flag_field_number_clashes(
+            merged,
+            &mut ClashContext {
+                sources,
+                conflicts,
+                scope: vec![],
+            },
+        ),
+        _ => merged,
+    }
+}
+
+/// What flagging the clashes of a node needs besides the node
+struct ClashContext<'c, 't> {
+    sources: &'c SourceTrees<'t>,
+    conflicts: &'c mut Vec<ConflictReport>,
+    /// Names of the messages and enums the node is in, from the outermost
+    scope: Vec<String>,
+}
+
+impl ClashContext<'_, '_> {
+    /// Reports a clash between `fields`, returning the index of its report
+    fn report(&mut self, fields: &[&MergedCSTNode]) -> usize {
+        let names_and_numbers: Vec<_> = fields
+            .iter()
+            .filter_map(|field| field_name_and_number(field))
+            .collect();
+        let locate = |tree: &CSTNode| {
+            names_and_numbers
+                .iter()
+                .find_map(|field| find_field(tree, &self.scope, field, &mut vec![]))
+                .map(LineRange::of_node)
+        };
+
+        self.conflicts.push(ConflictReport {
+            node_kind: fields
+                .first()
+                .and_then(|field| node_kind(field))
+                .map(String::from),
+            merge: ConflictMerge::FieldNumberClash,
+            left: locate(self.sources.left),
+            base: locate(self.sources.base),
+            right: locate(self.sources.right),
+            output: None,
+        });
+        self.conflicts.len() - 1
+    }
+}
+
+/// Fields are identified on the wire by their numbers, so a merge in which each side adds a
+/// field with the same number breaks compatibility even if it is textually clean. Such fields are
+/// placed in a conflict. Fields added with the same name are matched by it, so different numbers
+/// for them are already a conflict.
+fn flag_field_number_clashes<'a>(
+    node: MergedCSTNode<'a>,
+    context: &mut ClashContext,
+) -> MergedCSTNode<'a> {
+    let MergedCSTNode::NonTerminal {
+        kind,
+        children,
+        leading_white_space,
+    } = node
+    else {
+        return node;
+    };
+
+    let found = if PROTO_BODY_KINDS.contains(&kind) && !allows_alias(&children) {
+        find_clashes(&children)
+    } else {
+        Clashes::default()
+    };
+
+    let scope_name = children
+        .iter()
+        .find(|child| node_kind(child).is_some_and(|kind| PROTO_SCOPE_NAME_KINDS.contains(&kind)))
+        .map(text);
+    if let Some(scope_name) = &scope_name {
+        context.scope.push(scope_name.clone());
+    }
+
+    let taken: HashSet<usize> = found.with_sibling.values().flatten().copied().collect();
+    let mut children: Vec<Option<MergedCSTNode>> = children.into_iter().map(Some).collect();
+    let mut merged_children = Vec::with_capacity(children.len());
+    for index in 0..children.len() {
+        if taken.contains(&index) {
+            continue;
+        }
+        let child = children[index].take().expect("Each child is taken once");
+        if let Some(others) = found.with_sibling.get(&index) {
+            let others: Vec<_> = others
+                .iter()
+                .map(|other| children[*other].take().expect("Each child is taken once"))
+                .collect();
+            let record =
+                context.report(&std::iter::once(&child).chain(&others).collect::<Vec<_>>());
+            merged_children.push(MergedCSTNode::Conflict {
+                left: Some(Box::new(child)),
+                base: None,
+                right: Some(Box::new(join(kind, others))),
+                record: Some(record),
+            });
+        } else if found.alone.contains(&FieldLocation::Direct(index)) {
+            merged_children.push(flag_alone(child, context));
+        } else if let MergedCSTNode::NonTerminal {
+            kind: oneof_kind @ "oneof",
+            children: oneof_children,
+            leading_white_space,
+        } = child
+        {
+            let oneof_children = oneof_children
+                .into_iter()
+                .enumerate()
+                .map(|(inner, oneof_child)| {
+                    if found.alone.contains(&FieldLocation::InOneof(index, inner)) {
+                        flag_alone(oneof_child, context)
+                    } else {
+                        flag_field_number_clashes(oneof_child, context)
+                    }
+                })
+                .collect();
+            merged_children.push(MergedCSTNode::NonTerminal {
+                kind: oneof_kind,
+                children: oneof_children,
+                leading_white_space,
+            });
+        } else {
+            merged_children.push(flag_field_number_clashes(child, context));
+        }
+    }
+
+    if scope_name.is_some() {
+        context.scope.pop();
+    }
+    MergedCSTNode::NonTerminal {
+        kind,
+        children: merged_children,
+        leading_white_space,
+    }
+}
+
+/// Where a field is among the children of a message body: directly, or in one of its oneofs,
+/// whose fields share the numbers of the message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum FieldLocation {
+    Direct(usize),
+    InOneof(usize, usize),
+}
+
+/// The fields of a body that reuse the number of an earlier one
+#[derive(Debug, Default)]
+struct Clashes {
+    /// Direct children clashing with an earlier direct child, by the index of that child. They
+    /// are placed in a single conflict with it.
+    with_sibling: HashMap<usize, Vec<usize>>,
+    /// Fields clashing with one found elsewhere, which are placed in a conflict on their own
+    alone: HashSet<FieldLocation>,
+}
+
+fn find_clashes(children: &[MergedCSTNode]) -> Clashes {
+    let mut found = Clashes::default();
+    let mut first_with_number: HashMap<String, (FieldLocation, String)> = HashMap::new();
+    for (location, field) in fields(children) {
+        let Some((name, number)) = field_name_and_number(field) else {
+            continue;
+        };
+        match (first_with_number.get(&number), location) {
+            (Some((_, first_name)), _) if *first_name == name => {}
+            (Some((FieldLocation::Direct(first), _)), FieldLocation::Direct(index)) => {
+                found.with_sibling.entry(*first).or_default().push(index);
+            }
+            (Some(_), location) => {
+                found.alone.insert(location);
+            }
+            (None, location) => {
+                first_with_number.insert(number, (location, name));
+            }
+        }
+    }
+    found
+}
+
+/// The children of a body along with the fields of its oneofs, in the order they appear
+fn fields<'n, 'a>(
+    children: &'n [MergedCSTNode<'a>],
+) -> impl Iterator<Item = (FieldLocation, &'n MergedCSTNode<'a>)> {
+    children
+        .iter()
+        .enumerate()
+        .flat_map(|(index, child)| match child {
+            MergedCSTNode::NonTerminal {
+                kind: "oneof",
+                children,
+                ..
+            } => children
+                .iter()
+                .enumerate()
+                .map(|(inner, field)| (FieldLocation::InOneof(index, inner), field))
+                .collect(),
+            _ => vec![(FieldLocation::Direct(index), child)],
+        })
+}
+
+/// Places `field` in a conflict against nothing, so it is either kept or dropped. It goes on the
+/// side it was added by, so that taking that side keeps it.
+fn flag_alone<'a>(field: MergedCSTNode<'a>, context: &mut ClashContext) -> MergedCSTNode<'a> {
+    let record = context.report(&[&field]);
+    let is_from_left = field_name_and_number(&field).is_some_and(|name_and_number| {
+        let is_in =
+            |tree| find_field(tree, &context.scope, &name_and_number, &mut vec![]).is_some();
+        is_in(context.sources.left) && !is_in(context.sources.right)
+    });
+
+    let field = Some(Box::new(field));
+    let (left, right) = if is_from_left {
+        (field, None)
+    } else {
+        (None, field)
+    };
+    MergedCSTNode::Conflict {
+        left,
+        base: None,
+        right,
+        record: Some(record),
+    }
+}
+
+/// The field of `tree` with the given name and number, declared in the messages and enums named
+/// `scope`. `current_scope` holds the names of the ones `node` is in.
+fn find_field<'t>(
+    node: &'t CSTNode<'t>,
+    scope: &[String],
+    name_and_number: &(String, String),
+    current_scope: &mut Vec<String>,
+) -> Option<&'t CSTNode<'t>> {
+    let CSTNode::NonTerminal(non_terminal) = node else {
+        return None;
+    };
+    if PROTO_FIELD_KINDS.contains(&non_terminal.kind) {
+        return (current_scope.as_slice() == scope
+            && field_name_and_number(&MergedCSTNode::from(node)).as_ref()
+                == Some(name_and_number))
+        .then_some(node);
+    }
+
+    let scope_name = non_terminal
+        .children
+        .iter()
+        .find(|child| PROTO_SCOPE_NAME_KINDS.contains(&child.kind()))
+        .map(|name| text(&MergedCSTNode::from(name)));
+    let is_scope = scope_name.is_some();
+    current_scope.extend(scope_name);
+    let found = non_terminal
+        .children
+        .iter()
+        .find_map(|child| find_field(child, scope, name_and_number, current_scope));
+    if is_scope {
+        current_scope.pop();
+    }
+    found
+}
+
+/// Joins `fields` into a single side of a conflict. The white space before the first one goes
+/// before the whole side.
+fn join<'a>(kind: &'a str, mut fields: Vec<MergedCSTNode<'a>>) -> MergedCSTNode<'a> {
+    if fields.len() == 1 {
+        return fields.remove(0);
+    }
+
+    let leading_white_space = match fields.first_mut() {
+        Some(
+            MergedCSTNode::NonTerminal {
+                leading_white_space,
+                ..
+            }
+            | MergedCSTNode::Terminal {
+                leading_white_space,
+                ..
+            },
+        ) => leading_white_space.take(),
+        _ => None,
+    };
+    MergedCSTNode::NonTerminal {
+        kind,
+        children: fields,
+        leading_white_space,
+    }
+}
+
+/// Enums with `option allow_alias = true;` may give the same number to several values
+fn allows_alias(children: &[MergedCSTNode]) -> bool {
+    children.iter().any(|child| {
+        node_kind(child) == Some("option")
+            && text(child).replace(' ', "").contains("allow_alias=true")
+    })
+}
+
+fn field_name_and_number(node: &MergedCSTNode) -> Option<(String, String)> {
+    let MergedCSTNode::NonTerminal { kind, children, .. } = node else {
+        return None;
+    };
+    if !PROTO_FIELD_KINDS.contains(kind) || node.has_conflict() {
+        return None;
+    }
+
+    let name = children
+        .iter()
+        .find(|child| node_kind(child) == Some("identifier"))
+        .map(text)?;
+    let number = children
+        .iter()
+        .skip_while(|child| node_kind(child) != Some("="))
+        .skip(1)
+        .take_while(|child| !matches!(node_kind(child), Some("[" | ";")))
+        .map(text)
+        .collect();
+    Some((name, number))
+}
+
+fn node_kind<'a>(node: &MergedCSTNode<'a>) -> Option<&'a str> {
+    match node {
+        MergedCSTNode::Terminal { kind, .. } | MergedCSTNode::NonTerminal { kind, .. } => {
+            Some(kind)
+        }
+        MergedCSTNode::Conflict { .. } => None,
+    }
+}
+
+/// The values of the terminals under `node`, without the white space between them
+fn text(node: &MergedCSTNode) -> String {
+    match node {
+        MergedCSTNode::Terminal { value, .. } => value.to_string(),
+        MergedCSTNode::NonTerminal { children, .. } => children.iter().map(text).collect(),
+        MergedCSTNode::Conflict { .. } => String::new(),
+    }
+}
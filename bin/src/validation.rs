@@ -0,0 +1,123 @@
+use merge::RenderOptions;
+use parsing::{ParseError, ParserConfiguration, SyntaxDiagnostic};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Outside,
+    Left,
+    Base,
+    Right,
+}
+
+/// Re-parses a merged file looking for syntax errors introduced by the merge.
+///
+/// Conflicts are validated as if resolved in favor of the left side: marker lines and the other
+/// sections are blanked, so reported positions match the merged file. Errors lying entirely
+/// inside a conflict are not reported.
+pub fn validate_merged_output(
+    language: model::Language,
+    merged: &str,
+    render_options: &RenderOptions,
+) -> Result<Vec<SyntaxDiagnostic>, ParseError> {
+    validate_merged_output_with_configuration(
+        &ParserConfiguration::from(language),
+        merged,
+        render_options,
+    )
+}
+
+/// Same as [`validate_merged_output`], parsing with the given configuration
+pub fn validate_merged_output_with_configuration(
+    parser_configuration: &ParserConfiguration,
+    merged: &str,
+    render_options: &RenderOptions,
+) -> Result<Vec<SyntaxDiagnostic>, ParseError> {
+    let (source, conflict_rows) = blank_conflicts(merged, render_options.marker_size);
+    let parsed = parsing::parse_string(&source, parser_configuration)?;
+
+    Ok(parsed
+        .diagnostics
+        .into_iter()
+        .filter(|diagnostic| {
+            !conflict_rows.iter().any(|(start, end)| {
+                *start <= diagnostic.start_position.row && diagnostic.end_position.row <= *end
+            })
+        })
+        .collect())
+}
+
+fn blank_conflicts(merged: &str, marker_size: usize) -> (String, Vec<(usize, usize)>) {
+    let is_marker = |line: &str, character: char, labelled: bool| {
+        let line = line.trim_end_matches(['\n', '\r']);
+        let rest = line.trim_start_matches(character);
+        line.len() - rest.len() == marker_size
+            && (rest.is_empty() || (labelled && rest.starts_with(' ')))
+    };
+
+    let mut source = String::with_capacity(merged.len());
+    let mut conflict_rows = vec![];
+    let mut section = Section::Outside;
+    let mut conflict_start = 0;
+
+    for (row, line) in merged.split_inclusive('\n').enumerate() {
+        let (next_section, is_marker_line) = match section {
+            Section::Outside if is_marker(line, '<', true) => {
+                conflict_start = row;
+                (Section::Left, true)
+            }
+            Section::Left if is_marker(line, '|', true) => (Section::Base, true),
+            Section::Left | Section::Base if is_marker(line, '=', false) => (Section::Right, true),
+            Section::Right if is_marker(line, '>', true) => {
+                conflict_rows.push((conflict_start, row));
+                (Section::Outside, true)
+            }
+            _ => (section, false),
+        };
+
+        if is_marker_line || matches!(section, Section::Base | Section::Right) {
+            source.extend(line.chars().map(|c| if c == '\n' { c } else { ' ' }));
+        } else {
+            source.push_str(line);
+        }
+        section = next_section;
+    }
+
+    (source, conflict_rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::blank_conflicts;
+
+    #[test]
+    fn it_keeps_the_left_side_of_conflicts_and_blanks_everything_else() {
+        let merged = "a\n<<<<<<< left\nb\n||||||| base\nc\n=======\nd\n>>>>>>> right\ne\n";
+
+        let (source, conflict_rows) = blank_conflicts(merged, 7);
+
+        let blank = |size| " ".repeat(size);
+        assert_eq!(
+            source,
+            format!(
+                "a\n{}\nb\n{}\n{}\n{}\n{}\n{}\ne\n",
+                blank(12),
+                blank(12),
+                blank(1),
+                blank(7),
+                blank(1),
+                blank(13)
+            )
+        );
+        assert_eq!(conflict_rows, vec![(1, 7)]);
+    }
+
+    #[test]
+    fn it_only_recognizes_markers_of_the_given_size() {
+        let merged = "<<<<<<<<\na\n";
+
+        let (source, conflict_rows) = blank_conflicts(merged, 7);
+
+        assert_eq!(source, merged);
+        assert!(conflict_rows.is_empty());
+    }
+}
@@ -49,3 +49,349 @@ fn if_i_am_running_on_diff_mode_and_files_do_not_fully_match_it_returns_one() {
         .assert()
         .code(bin::SUCCESS_FILES_DO_NOT_FULLY_MATCH);
 }
+
+#[test]
+fn if_i_am_running_as_a_git_driver_it_writes_the_result_into_the_current_file() {
+    let current = "tests/scenarios/java/no_conflicts/merge.output.git_driver.java";
+    std::fs::copy("tests/scenarios/java/no_conflicts/left.java", current).unwrap();
+
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("git-driver")
+        .arg("tests/scenarios/java/no_conflicts/base.java")
+        .arg(current)
+        .arg("tests/scenarios/java/no_conflicts/right.java")
+        .arg("7")
+        .arg("src/main/java/Example.java")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let expected = std::fs::read_to_string("tests/scenarios/java/no_conflicts/merge.java").unwrap();
+    let result = std::fs::read_to_string(current).unwrap();
+    assert_eq!(expected.trim(), result.trim());
+}
+
+#[test]
+fn if_i_am_running_as_a_git_driver_the_written_file_keeps_its_final_line_break() {
+    let directory = std::env::temp_dir().join("last-merge-git-driver-final-line-break");
+    std::fs::create_dir_all(&directory).unwrap();
+    let write = |name: &str, contents: &str| {
+        let path = directory.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    };
+    let base = write("base.java", "class Main {\n    void a() {}\n}\n");
+    let current = write(
+        "current.java",
+        "class Main {\n    void a() {}\n\n    void b() {}\n}\n",
+    );
+    let other = write(
+        "other.java",
+        "class Main {\n    void a() {}\n\n    void c() {}\n}\n",
+    );
+
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("git-driver")
+        .arg(&base)
+        .arg(&current)
+        .arg(&other)
+        .arg("7")
+        .arg("Main.java")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    assert_eq!(
+        std::fs::read_to_string(&current).unwrap(),
+        "class Main {\n    void a() {}\n\n    void b() {}\n\n    void c() {}\n}\n"
+    );
+}
+
+#[test]
+fn if_i_am_running_as_a_git_driver_and_there_is_a_conflict_it_returns_valid_exit_code() {
+    let current = "tests/scenarios/java/smoke_java/merge.output.git_driver.java";
+    std::fs::copy("tests/scenarios/java/smoke_java/left.java", current).unwrap();
+
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("git-driver")
+        .arg("tests/scenarios/java/smoke_java/base.java")
+        .arg(current)
+        .arg("tests/scenarios/java/smoke_java/right.java")
+        .arg("7")
+        .arg("Example.java")
+        .assert()
+        .code(bin::SUCCESS_WITH_CONFLICTS);
+}
+
+#[test]
+fn if_i_am_running_as_a_git_driver_and_the_pathname_has_no_known_language_it_fails() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("git-driver")
+        .arg("tests/scenarios/java/smoke_java/base.java")
+        .arg("tests/scenarios/java/smoke_java/left.java")
+        .arg("tests/scenarios/java/smoke_java/right.java")
+        .arg("7")
+        .arg("README")
+        .assert()
+        .code(bin::INVALID_LANGUAGE_ERROR);
+}
+
+#[test]
+fn if_i_am_running_on_merge_file_mode_it_prints_conflicts_with_the_provided_labels() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    let assert = cmd
+        .arg("merge-file")
+        .arg("-p")
+        .arg("-L")
+        .arg("mine")
+        .arg("-L")
+        .arg("original")
+        .arg("-L")
+        .arg("yours")
+        .arg("--marker-size=9")
+        .arg("tests/scenarios/java/smoke_java/left.java")
+        .arg("tests/scenarios/java/smoke_java/base.java")
+        .arg("tests/scenarios/java/smoke_java/right.java")
+        .assert()
+        // As in git merge-file, the exit code is the number of conflicts
+        .code(2);
+
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("\n<<<<<<<<< mine\n"));
+    assert!(stdout.contains("\n=========\n"));
+    assert!(stdout.contains("\n>>>>>>>>> yours\n"));
+}
+
+#[test]
+fn if_i_am_running_on_merge_file_mode_favoring_a_side_there_are_no_conflicts() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    let assert = cmd
+        .arg("merge-file")
+        .arg("-p")
+        .arg("--ours")
+        .arg("tests/scenarios/java/smoke_java/left.java")
+        .arg("tests/scenarios/java/smoke_java/base.java")
+        .arg("tests/scenarios/java/smoke_java/right.java")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    assert!(!stdout.contains("<<<<<<<"));
+    assert!(stdout.contains("int y = 3;"));
+}
+
+#[test]
+fn if_i_am_running_on_merge_file_mode_with_diff3_it_prints_the_base_version() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    let assert = cmd
+        .arg("merge-file")
+        .arg("-p")
+        .arg("--diff3")
+        .arg("-L")
+        .arg("mine")
+        .arg("-L")
+        .arg("original")
+        .arg("-L")
+        .arg("yours")
+        .arg("tests/scenarios/java/left_deletes_changed_node_in_right/left.java")
+        .arg("tests/scenarios/java/left_deletes_changed_node_in_right/base.java")
+        .arg("tests/scenarios/java/left_deletes_changed_node_in_right/right.java")
+        .assert()
+        .code(bin::SUCCESS_WITH_CONFLICTS);
+
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("\n||||||| original\n"));
+}
+
+#[test]
+fn if_i_am_running_on_merge_file_mode_with_diff3_a_terminal_changed_in_both_sides_shows_its_base() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    let assert = cmd
+        .arg("merge-file")
+        .arg("-p")
+        .arg("--diff3")
+        .arg("-L")
+        .arg("mine")
+        .arg("-L")
+        .arg("original")
+        .arg("-L")
+        .arg("yours")
+        .arg("tests/scenarios/java/terminal_changed_in_both_sides/left.java")
+        .arg("tests/scenarios/java/terminal_changed_in_both_sides/base.java")
+        .arg("tests/scenarios/java/terminal_changed_in_both_sides/right.java")
+        .assert()
+        .code(bin::SUCCESS_WITH_CONFLICTS);
+
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("\n10\n||||||| original\n1\n=======\n20\n"));
+}
+
+#[test]
+fn if_i_am_running_in_hybrid_mode_broken_inputs_are_merged_textually() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("merge-file")
+        .arg("-p")
+        .arg("--hybrid")
+        .arg("tests/hybrid_scenarios/syntax_error/left.java")
+        .arg("tests/hybrid_scenarios/syntax_error/base.java")
+        .arg("tests/hybrid_scenarios/syntax_error/right.java")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS)
+        .stdout(std::fs::read_to_string("tests/hybrid_scenarios/syntax_error/merge.java").unwrap());
+}
+
+#[test]
+fn if_a_report_path_is_given_it_writes_the_report_as_json() {
+    let report_path = std::env::temp_dir().join("last-merge-smoke-java-report.json");
+
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("merge")
+        .arg("--base-path=tests/scenarios/java/smoke_java/base.java")
+        .arg("--left-path=tests/scenarios/java/smoke_java/left.java")
+        .arg("--right-path=tests/scenarios/java/smoke_java/right.java")
+        .arg("--merge-path=tests/scenarios/java/smoke_java/merge.output.java")
+        .arg("--language=java")
+        .arg(format!("--report={}", report_path.display()))
+        .assert()
+        .code(bin::SUCCESS_WITH_CONFLICTS);
+
+    let report = std::fs::read_to_string(report_path).unwrap();
+    assert!(report.contains("\"conflict_count\": 2"));
+    assert!(report.contains("\"node_kind\": \"string_literal\""));
+    assert!(report.contains("\"parse_ms\""));
+}
+
+#[test]
+fn if_a_language_definition_is_given_it_is_used_instead_of_the_built_in_language() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("merge-file")
+        .arg("-p")
+        .arg("-L")
+        .arg("current")
+        .arg("-L")
+        .arg("base")
+        .arg("-L")
+        .arg("other")
+        .arg("--language-definition=tests/language_definitions/java_ordered_members.toml")
+        .arg("tests/language_definitions/ordered_members/left.java")
+        .arg("tests/language_definitions/ordered_members/base.java")
+        .arg("tests/language_definitions/ordered_members/right.java")
+        .assert()
+        .code(bin::SUCCESS_WITH_CONFLICTS)
+        .stdout(
+            std::fs::read_to_string("tests/language_definitions/ordered_members/merge.java")
+                .unwrap(),
+        );
+
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("merge-file")
+        .arg("-p")
+        .arg("tests/language_definitions/ordered_members/left.java")
+        .arg("tests/language_definitions/ordered_members/base.java")
+        .arg("tests/language_definitions/ordered_members/right.java")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+}
+
+#[test]
+fn if_the_language_definition_is_invalid_it_fails() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("merge-file")
+        .arg("-p")
+        .arg("--language-definition=tests/language_definitions/unknown_grammar.toml")
+        .arg("tests/scenarios/java/smoke_java/left.java")
+        .arg("tests/scenarios/java/smoke_java/base.java")
+        .arg("tests/scenarios/java/smoke_java/right.java")
+        .assert()
+        .code(bin::INVALID_LANGUAGE_ERROR);
+}
+
+#[test]
+fn if_the_repository_has_a_configuration_file_its_settings_are_used() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    let assert = cmd
+        .current_dir("tests/repository_config/with_overrides")
+        .arg("merge-file")
+        .arg("-p")
+        .arg("src/left.java")
+        .arg("src/base.java")
+        .arg("src/right.java")
+        .assert()
+        .code(bin::SUCCESS_WITH_CONFLICTS);
+
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    // The class body is merged as a whole, so both added methods end up in one conflict
+    assert!(stdout.starts_with("public class Account\n<<<<<<< ours\n{\n"));
+    assert!(stdout.contains("\n<<<<<<< ours\n"));
+    assert!(stdout.contains("\n||||||| ancestor\n"));
+    assert!(stdout.contains("\n>>>>>>> theirs\n"));
+}
+
+#[test]
+fn if_the_repository_excludes_a_path_it_is_merged_textually() {
+    let report_path = std::env::temp_dir().join("last-merge-excluded-path-report.json");
+
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.current_dir("tests/repository_config/with_overrides")
+        .arg("merge-file")
+        .arg("-p")
+        .arg(format!("--report={}", report_path.display()))
+        .arg("generated/left.java")
+        .arg("generated/base.java")
+        .arg("generated/right.java")
+        .assert()
+        .code(bin::SUCCESS_WITH_CONFLICTS);
+
+    let report = std::fs::read_to_string(report_path).unwrap();
+    assert!(report.contains("\"strategy\": \"textual\""));
+    assert!(report.contains("excluded from structured merge"));
+}
+
+#[test]
+fn if_i_am_running_on_merge_mode_the_configuration_is_looked_up_from_the_merged_file() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("merge")
+        .arg("--base-path=tests/scenarios/java/smoke_java/base.java")
+        .arg("--left-path=tests/scenarios/java/smoke_java/left.java")
+        .arg("--right-path=tests/scenarios/java/smoke_java/right.java")
+        .arg("--merge-path=tests/repository_config/with_unknown_handler/src/merge.java")
+        .assert()
+        .code(bin::INVALID_CONFIGURATION_ERROR);
+}
+
+#[test]
+fn if_the_repository_configuration_enables_an_unknown_handler_it_fails() {
+    let mut cmd = Command::cargo_bin("last-merge").unwrap();
+    cmd.arg("merge-file")
+        .arg("-p")
+        .arg("tests/repository_config/with_unknown_handler/src/left.java")
+        .arg("tests/repository_config/with_unknown_handler/src/base.java")
+        .arg("tests/repository_config/with_unknown_handler/src/right.java")
+        .assert()
+        .code(bin::INVALID_CONFIGURATION_ERROR);
+}
+
+#[test]
+fn the_printed_chunks_preview_their_nodes_unless_compact_text_is_asked_for() {
+    let print_chunks = |format: Option<&str>| {
+        let mut cmd = Command::cargo_bin("last-merge").unwrap();
+        cmd.arg("merge")
+            .arg("--base-path=tests/scenarios/java/smoke_java/base.java")
+            .arg("--left-path=tests/scenarios/java/smoke_java/left.java")
+            .arg("--right-path=tests/scenarios/java/smoke_java/right.java")
+            .arg("--merge-path=tests/scenarios/java/smoke_java/merge.output.chunks.java")
+            .arg("--language=java")
+            .arg("--print-chunks");
+        if let Some(format) = format {
+            cmd.arg(format!("--chunk-log-format={format}"));
+        }
+        let assert = cmd.assert().code(bin::SUCCESS_WITH_CONFLICTS);
+        String::from_utf8(assert.get_output().stdout.clone()).unwrap()
+    };
+
+    let readable = print_chunks(None);
+    assert!(readable.contains("-- stable chunk #1 --"));
+    assert!(readable.contains("Left (L):  "));
+
+    let text = print_chunks(Some("text"));
+    assert!(!text.contains("-- stable chunk #1 --"));
+    assert!(text.contains("\n1\tS\t"));
+}
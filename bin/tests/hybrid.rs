@@ -0,0 +1,99 @@
+use std::fs::read_to_string;
+
+use bin::{
+    ExecutionConfig, ExecutionResult, FallbackReason, MergeStrategy, Revision, SyntaxErrorPolicy,
+};
+use model::Language;
+
+fn read_scenario(path: &str) -> (String, String, String) {
+    let read = |file: &str| read_to_string(format!("{path}/{file}")).unwrap();
+    (read("base.java"), read("left.java"), read("right.java"))
+}
+
+#[test]
+fn it_falls_back_to_textual_merge_if_an_input_has_syntax_errors() {
+    let (base, left, right) = read_scenario("tests/hybrid_scenarios/syntax_error");
+    let expected = read_to_string("tests/hybrid_scenarios/syntax_error/merge.java").unwrap();
+
+    let hybrid_result = bin::run_hybrid_tool_on_merge_scenario(
+        Language::Java,
+        &base,
+        &left,
+        &right,
+        &ExecutionConfig::default(),
+    );
+
+    assert_eq!(
+        hybrid_result.strategy,
+        MergeStrategy::Textual(FallbackReason::InputWithSyntaxErrors)
+    );
+    assert!(matches!(
+        hybrid_result.result,
+        ExecutionResult::WithoutConflicts(ref value) if value == &expected
+    ));
+}
+
+#[test]
+fn it_keeps_the_structured_result_if_it_is_not_worse_than_the_textual_one() {
+    let (base, left, right) = read_scenario("tests/scenarios/java/no_conflicts");
+    let expected = read_to_string("tests/scenarios/java/no_conflicts/merge.java").unwrap();
+
+    let hybrid_result = bin::run_hybrid_tool_on_merge_scenario(
+        Language::Java,
+        &base,
+        &left,
+        &right,
+        &ExecutionConfig::default(),
+    );
+
+    assert_eq!(hybrid_result.strategy, MergeStrategy::Structured);
+    assert_eq!(hybrid_result.result.to_string().trim(), expected.trim());
+}
+
+#[test]
+fn it_reports_syntax_errors_with_their_revision_if_asked_to_abort() {
+    let (base, left, right) = read_scenario("tests/hybrid_scenarios/syntax_error");
+
+    let error = bin::run_tool_on_merge_scenario(
+        Language::Java,
+        &base,
+        &left,
+        &right,
+        &ExecutionConfig {
+            on_syntax_error: SyntaxErrorPolicy::Abort,
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+
+    match error {
+        bin::ExecutionError::InputWithSyntaxErrors(diagnostics) => {
+            assert!(!diagnostics.is_empty());
+            assert!(diagnostics
+                .iter()
+                .all(|(revision, diagnostic)| *revision == Revision::Left
+                    && diagnostic.start_position.row == 2));
+        }
+        error => panic!("Unexpected error: {error}"),
+    }
+}
+
+#[test]
+fn it_merges_textually_if_asked_to_fall_back_on_syntax_errors() {
+    let (base, left, right) = read_scenario("tests/hybrid_scenarios/syntax_error");
+    let expected = read_to_string("tests/hybrid_scenarios/syntax_error/merge.java").unwrap();
+
+    let result = bin::run_tool_on_merge_scenario(
+        Language::Java,
+        &base,
+        &left,
+        &right,
+        &ExecutionConfig {
+            on_syntax_error: SyntaxErrorPolicy::Fallback,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert_eq!(result.to_string(), expected);
+}
@@ -0,0 +1,7 @@
+public class Main {
+    public static void main(String[] args) {
+        int x = 1;
+        int z = 3;
+        int y = 20;
+    }
+}
@@ -0,0 +1,7 @@
+public class Account {
+    private long balance;
+
+    long balance() {
+        return balance;
+    }
+}
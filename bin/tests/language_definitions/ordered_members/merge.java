@@ -0,0 +1,17 @@
+public class Account {
+    private long balance;
+
+    long balance() {
+        return balance;
+    }
+<<<<<<< current
+    void deposit(long amount) {
+        balance += amount;
+    }
+=======
+    void withdraw(long amount) {
+        balance -= amount;
+    }
+>>>>>>> other
+
+}
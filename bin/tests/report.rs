@@ -0,0 +1,166 @@
+use std::fs::read_to_string;
+
+use bin::{ConflictMerge, ExecutionConfig, LineRange, ReportedStrategy};
+use model::Language;
+
+fn read_scenario(path: &str) -> (String, String, String) {
+    let read = |file: &str| read_to_string(format!("{path}/{file}")).unwrap();
+    (read("base.java"), read("left.java"), read("right.java"))
+}
+
+#[test]
+fn it_reports_where_each_conflict_is_in_every_revision() {
+    let (base, left, right) = read_scenario("tests/scenarios/java/smoke_java");
+
+    let (_, report) = bin::run_tool_on_merge_scenario_with_report(
+        Language::Java,
+        &base,
+        &left,
+        &right,
+        &ExecutionConfig::default(),
+    )
+    .unwrap();
+
+    assert_eq!(report.strategy, ReportedStrategy::Structured);
+    assert_eq!(report.conflict_count, 2);
+
+    let conflict = &report.conflicts[0];
+    assert_eq!(conflict.node_kind.as_deref(), Some("string_literal"));
+    assert_eq!(conflict.merge, ConflictMerge::Ordered);
+    assert_eq!(
+        conflict.left,
+        Some(LineRange {
+            start_line: 6,
+            end_line: 6
+        })
+    );
+    assert_eq!(
+        conflict.right,
+        Some(LineRange {
+            start_line: 4,
+            end_line: 4
+        })
+    );
+    assert_eq!(
+        conflict.output,
+        Some(LineRange {
+            start_line: 7,
+            end_line: 11
+        })
+    );
+}
+
+#[test]
+fn it_reports_the_textual_fallback_and_its_reason() {
+    let (base, left, right) = read_scenario("tests/hybrid_scenarios/syntax_error");
+
+    let hybrid_result = bin::run_hybrid_tool_on_merge_scenario(
+        Language::Java,
+        &base,
+        &left,
+        &right,
+        &ExecutionConfig::default(),
+    );
+
+    assert_eq!(hybrid_result.report.strategy, ReportedStrategy::Textual);
+    assert_eq!(
+        hybrid_result.report.fallback_reason.as_deref(),
+        Some("at least one of the input files has syntax errors")
+    );
+    assert_eq!(hybrid_result.report.conflict_count, 0);
+}
+
+#[test]
+fn it_keeps_the_chunk_log_only_if_asked_to() {
+    let (base, left, right) = read_scenario("tests/scenarios/java/smoke_java");
+    let run = |config: &ExecutionConfig| {
+        bin::run_tool_on_merge_scenario_with_report(Language::Java, &base, &left, &right, config)
+            .unwrap()
+            .1
+    };
+
+    assert!(run(&ExecutionConfig::default()).chunk_log.is_none());
+
+    let report = run(&ExecutionConfig {
+        record_chunk_log: true,
+        ..Default::default()
+    });
+    let chunk_log = report.chunk_log.unwrap();
+    assert!(!chunk_log.chunks.is_empty());
+    assert!(chunk_log
+        .chunks
+        .iter()
+        .any(|chunk| chunk.unordered_context == vec!["class_body"]));
+}
+
+#[test]
+fn it_reports_fields_added_with_the_same_number_by_each_side() {
+    let path = "tests/scenarios/proto/same_field_number_added_in_both_sides";
+    let read = |file: &str| read_to_string(format!("{path}/{file}")).unwrap();
+
+    let hybrid_result = bin::run_hybrid_tool_on_merge_scenario(
+        Language::Proto,
+        &read("base.proto"),
+        &read("left.proto"),
+        &read("right.proto"),
+        &ExecutionConfig::default(),
+    );
+
+    assert_eq!(hybrid_result.report.strategy, ReportedStrategy::Structured);
+    assert_eq!(hybrid_result.report.conflict_count, 1);
+    let conflict = &hybrid_result.report.conflicts[0];
+    assert_eq!(conflict.node_kind.as_deref(), Some("field"));
+    assert_eq!(conflict.merge, ConflictMerge::FieldNumberClash);
+    assert_eq!(
+        conflict.output,
+        Some(LineRange {
+            start_line: 6,
+            end_line: 10
+        })
+    );
+}
+
+#[test]
+fn it_locates_each_conflict_by_the_nodes_it_was_raised_for() {
+    let base = "syntax = \"proto3\";\n\nmessage User {\n  // The name\n  string name = 1;\n}\n\nmessage Account {\n  int32 id = 1;\n}\n";
+    let left = "syntax = \"proto3\";\n\nmessage User {\n  // The full name\n  string name = 1;\n}\n\nmessage Account {\n  int32 id = 1;\n  string email = 2;\n}\n";
+    let right = "syntax = \"proto3\";\n\nmessage User {\n  // The display name\n  string name = 1;\n}\n\nmessage Account {\n  int32 id = 1;\n  int64 created_at = 2;\n}\n";
+
+    let (result, report) = bin::run_tool_on_merge_scenario_with_report(
+        Language::Proto,
+        base,
+        left,
+        right,
+        &ExecutionConfig::default(),
+    )
+    .unwrap();
+    let output = result.to_string();
+    let output_lines: Vec<&str> = output.lines().collect();
+    let rendered = |range: Option<LineRange>| {
+        let range = range.expect("Expected the conflict to be in the output");
+        output_lines[range.start_line - 1..range.end_line].join("\n")
+    };
+
+    assert_eq!(report.conflict_count, 2);
+    let trivia = &report.conflicts[0];
+    assert_eq!(trivia.merge, ConflictMerge::LeadingTrivia);
+    assert!(rendered(trivia.output).contains("// The full name"));
+    let clash = &report.conflicts[1];
+    assert_eq!(clash.merge, ConflictMerge::FieldNumberClash);
+    assert!(rendered(clash.output).contains("string email = 2;"));
+    assert_eq!(
+        clash.left,
+        Some(LineRange {
+            start_line: 10,
+            end_line: 10
+        })
+    );
+    assert_eq!(clash.base, None);
+    assert_eq!(
+        clash.right,
+        Some(LineRange {
+            start_line: 10,
+            end_line: 10
+        })
+    );
+}
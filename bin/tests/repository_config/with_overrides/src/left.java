@@ -0,0 +1,11 @@
+public class Account {
+    private long balance;
+
+    long balance() {
+        return balance;
+    }
+
+    void deposit(long amount) {
+        balance += amount;
+    }
+}
@@ -26,6 +26,66 @@ fn go_samples(#[files("tests/scenarios/go/**/merge.go")] path: PathBuf) {
     run_sample(path.parent().unwrap(), Language::Go, "go");
 }
 
+#[rstest]
+fn typescript_samples(#[files("tests/scenarios/typescript/**/merge.ts")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::TypeScript, "ts");
+}
+
+#[rstest]
+fn tsx_samples(#[files("tests/scenarios/typescript/**/merge.tsx")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::Tsx, "tsx");
+}
+
+#[rstest]
+fn python_samples(#[files("tests/scenarios/python/**/merge.py")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::Python, "py");
+}
+
+#[rstest]
+fn rust_samples(#[files("tests/scenarios/rust/**/merge.rs")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::Rust, "rs");
+}
+
+#[rstest]
+fn kotlin_samples(#[files("tests/scenarios/kotlin/**/merge.kt")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::Kotlin, "kt");
+}
+
+#[rstest]
+fn c_samples(#[files("tests/scenarios/c/**/merge.c")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::C, "c");
+}
+
+#[rstest]
+fn cpp_samples(#[files("tests/scenarios/cpp/**/merge.cpp")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::Cpp, "cpp");
+}
+
+#[rstest]
+fn json_samples(#[files("tests/scenarios/json/**/merge.json")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::Json, "json");
+}
+
+#[rstest]
+fn yaml_samples(#[files("tests/scenarios/yaml/**/merge.yaml")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::Yaml, "yaml");
+}
+
+#[rstest]
+fn toml_samples(#[files("tests/scenarios/toml/**/merge.toml")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::Toml, "toml");
+}
+
+#[rstest]
+fn xml_samples(#[files("tests/scenarios/xml/**/merge.xml")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::Xml, "xml");
+}
+
+#[rstest]
+fn proto_samples(#[files("tests/scenarios/proto/**/merge.proto")] path: PathBuf) {
+    run_sample(path.parent().unwrap(), Language::Proto, "proto");
+}
+
 fn run_sample(path: &Path, language: Language, extension: &str) {
     use std::fs::read_to_string;
     let base =
@@ -37,8 +97,14 @@ fn run_sample(path: &Path, language: Language, extension: &str) {
     let expected =
         read_to_string(path.join(format!("merge.{}", extension))).expect("Could not read merge");
 
-    let result = bin::run_tool_on_merge_scenario(language, &base, &left, &right, false)
-        .expect("Unknown error during merge");
+    let result = bin::run_tool_on_merge_scenario(
+        language,
+        &base,
+        &left,
+        &right,
+        &bin::ExecutionConfig::default(),
+    )
+    .expect("Unknown error during merge");
 
     assert_eq!(expected.trim(), result.to_string().trim());
 }
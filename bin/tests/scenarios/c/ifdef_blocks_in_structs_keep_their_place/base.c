@@ -0,0 +1,7 @@
+struct connection {
+    int fd;
+#ifdef USE_TLS
+    void *tls_context;
+#endif
+    int timeout;
+};
@@ -0,0 +1,8 @@
+struct connection {
+    int fd;
+#ifdef USE_TLS
+    void *tls_context;
+#endif
+    int timeout;
+    int retries;
+};
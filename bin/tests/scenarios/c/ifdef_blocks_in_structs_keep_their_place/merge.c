@@ -0,0 +1,10 @@
+struct connection {
+    int fd;
+    int port;
+#ifdef USE_TLS
+    void *tls_context;
+    int tls_version;
+#endif
+    int timeout;
+    int retries;
+};
@@ -0,0 +1,9 @@
+struct connection {
+    int fd;
+    int port;
+#ifdef USE_TLS
+    void *tls_context;
+    int tls_version;
+#endif
+    int timeout;
+};
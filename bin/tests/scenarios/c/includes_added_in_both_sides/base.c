@@ -0,0 +1,6 @@
+#include <stdio.h>
+#include "list.h"
+
+int main(void) {
+    return 0;
+}
@@ -0,0 +1,7 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include "list.h"
+
+int main(void) {
+    return 0;
+}
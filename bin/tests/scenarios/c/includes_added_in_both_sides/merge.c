@@ -0,0 +1,8 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include "list.h"
+#include "queue.h"
+
+int main(void) {
+    return 0;
+}
@@ -0,0 +1,7 @@
+#include <stdio.h>
+#include "list.h"
+#include "queue.h"
+
+int main(void) {
+    return 0;
+}
@@ -0,0 +1,6 @@
+struct node {
+    int value;
+    struct node *next;
+};
+
+int length(struct node *head);
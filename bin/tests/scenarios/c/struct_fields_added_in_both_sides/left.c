@@ -0,0 +1,7 @@
+struct node {
+    int value;
+    struct node *next;
+    struct node *prev;
+};
+
+int length(struct node *head);
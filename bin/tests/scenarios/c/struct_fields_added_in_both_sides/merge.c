@@ -0,0 +1,9 @@
+struct node {
+    int value;
+    struct node *next;
+    struct node *prev;
+    unsigned flags;
+};
+
+int length(struct node *head);
+int sum(struct node *head);
@@ -0,0 +1,8 @@
+struct node {
+    int value;
+    struct node *next;
+    unsigned flags;
+};
+
+int length(struct node *head);
+int sum(struct node *head);
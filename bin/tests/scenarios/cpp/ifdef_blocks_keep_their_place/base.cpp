@@ -0,0 +1,9 @@
+#include <string>
+
+struct Config {
+    std::string name;
+#ifdef ENABLE_LOGGING
+    bool verbose;
+#endif
+    int retries;
+};
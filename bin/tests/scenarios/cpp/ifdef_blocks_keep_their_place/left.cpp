@@ -0,0 +1,11 @@
+#include <string>
+#include <vector>
+
+struct Config {
+    std::string name;
+#ifdef ENABLE_LOGGING
+    bool verbose;
+    int level;
+#endif
+    int retries;
+};
@@ -0,0 +1,13 @@
+#include <string>
+#include <vector>
+
+struct Config {
+    std::string name;
+    std::string host;
+#ifdef ENABLE_LOGGING
+    bool verbose;
+    int level;
+#endif
+    int retries;
+    int timeout_ms;
+};
@@ -0,0 +1,11 @@
+#include <string>
+
+struct Config {
+    std::string name;
+    std::string host;
+#ifdef ENABLE_LOGGING
+    bool verbose;
+#endif
+    int retries;
+    int timeout_ms;
+};
@@ -0,0 +1,8 @@
+class Shape {
+public:
+    virtual ~Shape() = default;
+    virtual double area() const = 0;
+
+private:
+    int id_;
+};
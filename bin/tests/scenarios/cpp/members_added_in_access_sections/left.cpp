@@ -0,0 +1,9 @@
+class Shape {
+public:
+    virtual ~Shape() = default;
+    virtual double area() const = 0;
+    virtual double perimeter() const = 0;
+
+private:
+    int id_;
+};
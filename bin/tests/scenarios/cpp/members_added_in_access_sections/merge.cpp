@@ -0,0 +1,10 @@
+class Shape {
+public:
+    virtual ~Shape() = default;
+    virtual double area() const = 0;
+    virtual double perimeter() const = 0;
+
+private:
+    int id_;
+    std::string name_;
+};
@@ -0,0 +1,9 @@
+class Shape {
+public:
+    virtual ~Shape() = default;
+    virtual double area() const = 0;
+
+private:
+    int id_;
+    std::string name_;
+};
@@ -0,0 +1,4 @@
+using System;
+using System.IO;
+
+namespace N { class A {} }
@@ -0,0 +1,5 @@
+using System;
+using System.IO;
+using System.Linq;
+
+namespace N { class A {} }
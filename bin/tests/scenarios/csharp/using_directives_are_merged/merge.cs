@@ -0,0 +1,6 @@
+using System;
+using System.IO;
+using System.Linq;
+using System.Text;
+
+namespace N { class A {} }
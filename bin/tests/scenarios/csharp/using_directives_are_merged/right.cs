@@ -0,0 +1,5 @@
+using System;
+using System.Text;
+using System.IO;
+
+namespace N { class A {} }
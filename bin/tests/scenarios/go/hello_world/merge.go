@@ -1,4 +1,5 @@
 package main
 
 func Main() {
-	z := 0 + 1}
\ No newline at end of file
+	z := 0 + 1
+}
\ No newline at end of file
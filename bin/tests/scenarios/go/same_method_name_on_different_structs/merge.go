@@ -5,7 +5,9 @@ type User struct{}
 type Product struct{}
 
 func (u *User) Validate() bool {
-	return true}
+	return true
+}
 
 func (p *Product) Validate() bool {
-	return true}
\ No newline at end of file
+	return true
+}
\ No newline at end of file
@@ -9,6 +9,12 @@ import de.ovgu.cide.fstgen.ast.FSTNode;
 import de.ovgu.cide.fstgen.ast.FSTNonTerminal;
 import de.ovgu.cide.fstgen.ast.FSTTerminal;
 
+/**
+ * Class representing a textual merge conflict.
+ * 
+ * @author Guilherme
+ */
+
 public class MergeConflict {
 
 	private final String left;
@@ -102,18 +108,38 @@ public class MergeConflict {
 	public String toString() {
 		return this.body;
 	}
+
+	/**
+	 * @return the LEFT conflicting content
+	 */
 	public String getLeft() {
 		return left;
 	}
+
+	/**
+	 * @return the BASE conflicting content
+	 */
 	public String getBase() {
 		return base;
 	}
+
+	/**
+	 * @return the YOURS conflicting content
+	 */
 	public String getRight() {
 		return right;
 	}
+
+	/**
+	 * @return the startLOC of the conflict
+	 */
 	public int getStartLOC() {
 		return startLOC;
 	}
+	
+	/**
+	 * @return the endLOC
+	 */
 	public int getEndLOC() {
 		return endLOC;
 	}
@@ -137,4 +163,24 @@ public class MergeConflict {
 		this.startLOC = startLOC;
 		this.endLOC = endLOC;
 	}
+
+	/*
+	 * public boolean containsRelaxed(String leftPattern, String rightPattern){
+	 * if(leftPattern.isEmpty() || rightPattern.isEmpty()){ return false; } else {
+	 * leftPattern =
+	 * (leftPattern.replaceAll("\\r\\n|\\r|\\n","")).replaceAll("\\s+","");
+	 * rightPattern =
+	 * (rightPattern.replaceAll("\\r\\n|\\r|\\n","")).replaceAll("\\s+",""); String
+	 * lefttrim = (this.left.replaceAll("\\r\\n|\\r|\\n","")).replaceAll("\\s+","");
+	 * String righttrim =
+	 * (this.right.replaceAll("\\r\\n|\\r|\\n","")).replaceAll("\\s+","");
+	 * 
+	 * leftPattern = Util.removeReservedKeywords(leftPattern); rightPattern =
+	 * Util.removeReservedKeywords(rightPattern); lefttrim =
+	 * Util.removeReservedKeywords(lefttrim); righttrim =
+	 * Util.removeReservedKeywords(righttrim);
+	 * 
+	 * return (lefttrim.contains(leftPattern) && righttrim.contains(rightPattern));
+	 * } }
+	 */
 }
\ No newline at end of file
@@ -0,0 +1,21 @@
+/*
+ * Copyright (c) 2020 Example
+ */
+package example;
+
+/**
+ * A calculator.
+ */
+public class Calculator {
+    /**
+     * Adds two numbers.
+     */
+    public int add(int a, int b) {
+        return a + b;
+    }
+
+    // Subtracts two numbers
+    public int subtract(int a, int b) {
+        return a - b;
+    }
+}
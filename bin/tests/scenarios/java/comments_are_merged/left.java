@@ -0,0 +1,28 @@
+/*
+ * Copyright (c) 2024 Example
+ */
+package example;
+
+/**
+ * A calculator.
+ */
+public class Calculator {
+    /**
+     * Adds two numbers.
+     */
+    public int add(int a, int b) {
+        return a + b;
+    }
+
+    // Subtracts two numbers
+    public int subtract(int a, int b) {
+        return a - b;
+    }
+
+    /**
+     * Multiplies two numbers.
+     */
+    public int multiply(int a, int b) {
+        return a * b;
+    }
+}
@@ -0,0 +1,30 @@
+/*
+ * Copyright (c) 2024 Example
+ */
+package example;
+
+/**
+ * A simple calculator.
+ */
+public class Calculator {
+    /**
+     * Adds two integers.
+     *
+     * @return their sum
+     */
+    public int add(int a, int b) {
+        return b + a;
+    }
+
+    // Subtracts two numbers
+    public int subtract(int a, int b) {
+        return a - b;
+    }
+
+    /**
+     * Multiplies two numbers.
+     */
+    public int multiply(int a, int b) {
+        return a * b;
+    }
+}
\ No newline at end of file
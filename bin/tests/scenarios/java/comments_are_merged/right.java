@@ -0,0 +1,23 @@
+/*
+ * Copyright (c) 2020 Example
+ */
+package example;
+
+/**
+ * A simple calculator.
+ */
+public class Calculator {
+    /**
+     * Adds two integers.
+     *
+     * @return their sum
+     */
+    public int add(int a, int b) {
+        return b + a;
+    }
+
+    // Subtracts two numbers
+    public int subtract(int a, int b) {
+        return a - b;
+    }
+}
@@ -1,7 +1,7 @@
 class Test {
 <<<<<<<
 =======
-public static void main() {
+    public static void main() {
         System.out.println("Hello, Joao!");
     }
 >>>>>>>
@@ -0,0 +1,11 @@
+class Test {
+    /**
+     * Greets the world
+     */
+    public static void main() {
+        System.out.println("Hello, world!");
+    }
+
+    void other() {
+    }
+}
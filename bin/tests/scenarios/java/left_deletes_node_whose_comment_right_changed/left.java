@@ -0,0 +1,4 @@
+class Test {
+    void other() {
+    }
+}
@@ -0,0 +1,14 @@
+class Test {
+    void other() {
+    }
+<<<<<<<
+=======
+    /**
+     * Greets the whole world
+     */
+    public static void main() {
+        System.out.println("Hello, world!");
+    }
+>>>>>>>
+
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+class Test {
+    /**
+     * Greets the whole world
+     */
+    public static void main() {
+        System.out.println("Hello, world!");
+    }
+
+    void other() {
+    }
+}
@@ -1,6 +1,6 @@
 class Test {
 <<<<<<<
-public static void main() {
+    public static void main() {
         System.out.println("Hello, Joao!");
     }
 =======
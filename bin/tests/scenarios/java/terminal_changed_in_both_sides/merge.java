@@ -0,0 +1,9 @@
+class A {
+    int x =
+<<<<<<<
+10
+=======
+20
+>>>>>>>
+;
+}
@@ -0,0 +1,3 @@
+const config = {
+    host: 'localhost'
+}
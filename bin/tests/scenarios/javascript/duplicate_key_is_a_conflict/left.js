@@ -0,0 +1,4 @@
+const config = {
+    host: 'localhost',
+    retries: 3
+}
@@ -0,0 +1,10 @@
+const config = {
+    host: 'localhost',
+<<<<<<<
+    retries: 3
+=======
+    retries: 4,
+    retries: 5
+>>>>>>>
+
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+const config = {
+    host: 'localhost',
+    retries: 4,
+    retries: 5
+}
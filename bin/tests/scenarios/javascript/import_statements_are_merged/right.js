@@ -0,0 +1,4 @@
+import c from 'c';
+import a from 'a';
+
+const x = 1;
@@ -0,0 +1,7 @@
+package com.example.data
+
+class UserRepository(private val api: UserApi) {
+    private val cache = mutableMapOf<String, User>()
+
+    fun find(id: String): User? = cache[id]
+}
@@ -0,0 +1,8 @@
+package com.example.data
+
+class UserRepository(private val api: UserApi) {
+    private val cache = mutableMapOf<String, User>()
+    private var lastRefresh: Long = 0
+
+    fun find(id: String): User? = cache[id]
+}
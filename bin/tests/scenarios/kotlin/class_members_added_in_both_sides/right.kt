@@ -0,0 +1,11 @@
+package com.example.data
+
+class UserRepository(private val api: UserApi) {
+    private val cache = mutableMapOf<String, User>()
+
+    fun find(id: String): User? = cache[id]
+
+    fun clear() {
+        cache.clear()
+    }
+}
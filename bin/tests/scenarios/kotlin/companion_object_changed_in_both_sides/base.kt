@@ -0,0 +1,7 @@
+class Session {
+    val id: String = newId()
+
+    companion object {
+        const val TAG = "Session"
+    }
+}
@@ -0,0 +1,8 @@
+class Session {
+    val id: String = newId()
+
+    companion object {
+        const val TAG = "Session"
+        const val TIMEOUT_MS = 30_000L
+    }
+}
@@ -0,0 +1,11 @@
+class Session {
+    val id: String = newId()
+
+    companion object {
+        const val TAG = "Session"
+        const val TIMEOUT_MS = 30_000L
+
+        fun create(): Session = Session()
+    }
+    val createdAt: Long = System.currentTimeMillis()
+}
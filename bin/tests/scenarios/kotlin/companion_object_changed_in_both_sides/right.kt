@@ -0,0 +1,10 @@
+class Session {
+    val id: String = newId()
+    val createdAt: Long = System.currentTimeMillis()
+
+    companion object {
+        const val TAG = "Session"
+
+        fun create(): Session = Session()
+    }
+}
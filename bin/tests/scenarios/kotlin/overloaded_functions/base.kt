@@ -0,0 +1,5 @@
+object Formatter {
+    fun format(value: Int): String = value.toString()
+
+    fun format(value: Double): String = "%.2f".format(value)
+}
@@ -0,0 +1,5 @@
+object Formatter {
+    fun format(value: Int): String = "%,d".format(value)
+
+    fun format(value: Double): String = "%.3f".format(value)
+}
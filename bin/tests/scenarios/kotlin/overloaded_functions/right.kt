@@ -0,0 +1,5 @@
+object Formatter {
+    fun format(value: Double): String = "%.3f".format(value)
+
+    fun format(value: Int): String = value.toString()
+}
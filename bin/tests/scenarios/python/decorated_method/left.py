@@ -0,0 +1,10 @@
+class Circle:
+    def __init__(self, radius):
+        self.radius = radius
+
+    @property
+    def diameter(self):
+        return 2 * self.radius
+
+    def area(self):
+        return 3.14 * self.radius ** 2
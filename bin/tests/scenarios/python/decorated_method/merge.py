@@ -0,0 +1,14 @@
+class Circle:
+    def __init__(self, radius):
+        self.radius = radius
+
+    @property
+    def diameter(self):
+        return 2 * self.radius
+
+    def area(self):
+        return 3.14159 * self.radius ** 2
+
+    @staticmethod
+    def unit():
+        return Circle(1)
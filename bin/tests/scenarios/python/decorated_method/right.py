@@ -0,0 +1,10 @@
+class Circle:
+    def __init__(self, radius):
+        self.radius = radius
+
+    def area(self):
+        return 3.14159 * self.radius ** 2
+
+    @staticmethod
+    def unit():
+        return Circle(1)
@@ -0,0 +1,6 @@
+import os
+from typing import List
+
+
+def list_files(path) -> List[str]:
+    return os.listdir(path)
@@ -0,0 +1,7 @@
+import os
+import sys
+from typing import List
+
+
+def list_files(path) -> List[str]:
+    return os.listdir(path)
@@ -0,0 +1,8 @@
+import os
+import sys
+from typing import List
+from pathlib import Path
+
+
+def list_files(path) -> List[str]:
+    return os.listdir(path)
@@ -0,0 +1,7 @@
+import os
+from typing import List
+from pathlib import Path
+
+
+def list_files(path) -> List[str]:
+    return os.listdir(path)
@@ -0,0 +1,10 @@
+class Account:
+    def __init__(self, owner):
+        self.owner = owner
+        self.balance = 0
+
+    def deposit(self, amount):
+        self.balance += amount
+
+    def statement(self):
+        return f"{self.owner}: {self.balance}"
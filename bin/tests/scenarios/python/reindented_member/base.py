@@ -0,0 +1,5 @@
+class Config:
+    name = "default"
+
+    def describe(self):
+        return self.name
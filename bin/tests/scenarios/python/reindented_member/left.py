@@ -0,0 +1,6 @@
+class Config:
+    name = "default"
+    debug = False
+
+    def describe(self):
+        return self.name
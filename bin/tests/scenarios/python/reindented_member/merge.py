@@ -0,0 +1,10 @@
+class Config:
+    name = "default"
+    debug = False
+
+    def describe(self):
+        return self.name
+
+    def reset(self):
+      self.name = "default"
+      return self
@@ -0,0 +1,9 @@
+class Config:
+  name = "default"
+
+  def describe(self):
+    return self.name
+
+  def reset(self):
+    self.name = "default"
+    return self
@@ -0,0 +1,6 @@
+impl Parser {
+    #[inline]
+    fn peek(&self) -> char {
+        self.current
+    }
+}
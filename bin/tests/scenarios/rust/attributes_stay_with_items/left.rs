@@ -0,0 +1,11 @@
+impl Parser {
+    #[inline]
+    fn peek(&self) -> char {
+        self.current
+    }
+
+    #[must_use]
+    fn is_done(&self) -> bool {
+        self.position >= self.input.len()
+    }
+}
@@ -0,0 +1,16 @@
+impl Parser {
+    #[inline]
+    fn peek(&self) -> char {
+        self.current
+    }
+
+    #[must_use]
+    fn is_done(&self) -> bool {
+        self.position >= self.input.len()
+    }
+    /// Moves to the next character
+    #[cfg(feature = "streaming")]
+    fn advance(&mut self) {
+        self.position += 1;
+    }
+}
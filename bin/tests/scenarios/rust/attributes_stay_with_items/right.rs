@@ -0,0 +1,12 @@
+impl Parser {
+    /// Moves to the next character
+    #[cfg(feature = "streaming")]
+    fn advance(&mut self) {
+        self.position += 1;
+    }
+
+    #[inline]
+    fn peek(&self) -> char {
+        self.current
+    }
+}
@@ -0,0 +1,9 @@
+pub struct Slot<T> {
+    value: T,
+}
+
+impl<T> Slot<T> {
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+}
@@ -0,0 +1,13 @@
+pub struct Slot<T> {
+    value: T,
+}
+
+impl<T> Slot<T> {
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    pub fn value(self) -> T {
+        self.value
+    }
+}
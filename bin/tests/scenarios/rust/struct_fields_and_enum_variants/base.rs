@@ -0,0 +1,6 @@
+pub struct Config {
+    pub name: String,
+    pub verbose: bool,
+}
+
+pub enum Mode { Fast, Safe }
@@ -0,0 +1,7 @@
+pub struct Config {
+    pub name: String,
+    pub verbose: bool,
+    pub threads: usize,
+}
+
+pub enum Mode { Fast, Safe, Debug }
@@ -0,0 +1,8 @@
+pub struct Config {
+    pub name: String,
+    pub verbose: bool,
+    pub threads: usize,
+    pub output: Option<String>,
+}
+
+pub enum Mode { Fast, Safe, Debug, Release }
@@ -0,0 +1,7 @@
+pub struct Config {
+    pub name: String,
+    pub verbose: bool,
+    pub output: Option<String>,
+}
+
+pub enum Mode { Fast, Safe, Release }
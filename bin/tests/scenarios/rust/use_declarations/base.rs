@@ -0,0 +1,4 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use std::collections::{BTreeMap,HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::io::Read;
+use std::path::Path;
+
+fn main() {}
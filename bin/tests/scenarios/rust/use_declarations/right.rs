@@ -0,0 +1,5 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::path::Path;
+
+fn main() {}
@@ -0,0 +1,9 @@
+export class UserService {
+  private readonly users: User[] = [];
+
+  constructor(private readonly http: HttpClient) {}
+
+  find(id: string): User | undefined {
+    return this.users.find((user) => user.id === id);
+  }
+}
@@ -0,0 +1,14 @@
+export class UserService {
+  private readonly users: User[] = [];
+  private cache = new Map<string, User>();
+
+  constructor(private readonly http: HttpClient) {}
+
+  find(id: string): User | undefined {
+    return this.users.find((user) => user.id === id);
+  }
+
+  clear(): void {
+    this.cache.clear();
+  }
+}
@@ -0,0 +1,14 @@
+export class UserService {
+  private readonly users: User[] = [];
+  private loading = false;
+
+  constructor(private readonly http: HttpClient) {}
+
+  find(id: string): User | undefined {
+    return this.users.find((user) => user.id === id);
+  }
+
+  async load(): Promise<void> {
+    this.loading = true;
+  }
+}
@@ -0,0 +1,5 @@
+export enum Status {
+  Active,
+  Inactive,
+  Pending = 3,
+}
@@ -0,0 +1,6 @@
+export enum Status {
+  Active,
+  Inactive,
+  Pending = 3,
+  Banned = 4
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+export class Counter {
+  private count = 0;
+
+  increment(): number {
+    return ++this.count;
+  }
+}
@@ -0,0 +1,8 @@
+export class Counter {
+  private count = 1;
+
+  increment(): number {
+    this.count += 1;
+    return this.count;
+  }
+}
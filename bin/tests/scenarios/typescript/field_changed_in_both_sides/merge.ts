@@ -0,0 +1,14 @@
+export class Counter {
+  private count =
+<<<<<<<
+1
+=======
+2
+>>>>>>>
+;
+
+  increment(step = 1): number {
+    this.count += 1;
+    return this.count;
+  }
+}
\ No newline at end of file
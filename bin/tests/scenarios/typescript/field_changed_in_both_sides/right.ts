@@ -0,0 +1,7 @@
+export class Counter {
+  private count = 2;
+
+  increment(step = 1): number {
+    return ++this.count;
+  }
+}
@@ -0,0 +1,6 @@
+export interface User {
+  id: string;
+  name: string;
+  email?: string;
+  createdAt: Date;
+}
\ No newline at end of file
@@ -0,0 +1 @@
+type Options = { verbose: boolean, retries: number, signal: AbortSignal };
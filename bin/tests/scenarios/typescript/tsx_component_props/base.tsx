@@ -0,0 +1,9 @@
+import React from "react";
+
+interface ButtonProps {
+  label: string;
+}
+
+export function Button({ label }: ButtonProps) {
+  return <button className="btn">{label}</button>;
+}
@@ -0,0 +1,10 @@
+import React from "react";
+
+interface ButtonProps {
+  label: string;
+  disabled?: boolean;
+}
+
+export function Button({ label }: ButtonProps) {
+  return <button className="btn">{label}</button>;
+}
@@ -0,0 +1,11 @@
+import React from "react";
+
+interface ButtonProps {
+  label: string;
+  disabled?: boolean;
+  onClick: () => void;
+}
+
+export function Button({ label }: ButtonProps) {
+  return <button className="btn">{label}</button>;
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+import React from "react";
+
+interface ButtonProps {
+  label: string;
+  onClick: () => void;
+}
+
+export function Button({ label }: ButtonProps) {
+  return <button className="btn">{label}</button>;
+}
@@ -0,0 +1,24 @@
+use merge::RenderOptions;
+use model::{Language, Point};
+
+#[test]
+fn conflicting_regions_do_not_make_the_merged_output_invalid() {
+    let merged = "class Main {\n<<<<<<<\n  int x = 1;\n=======\n  int x = 2\n>>>>>>>\n}\n";
+
+    let diagnostics =
+        bin::validate_merged_output(Language::Java, merged, &RenderOptions::default()).unwrap();
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn syntax_errors_outside_conflicts_are_located_in_the_merged_output() {
+    let merged =
+        "class Main {\n<<<<<<<\n  int x = 1;\n=======\n  int x = 2;\n>>>>>>>\n  int y = 3\n}\n";
+
+    let diagnostics =
+        bin::validate_merged_output(Language::Java, merged, &RenderOptions::default()).unwrap();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].start_position, Point { row: 6, column: 11 });
+}
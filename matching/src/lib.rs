@@ -7,5 +7,6 @@ mod ordered;
 mod unordered;
 
 pub use calculate::calculate_matchings;
+pub use matching::Matching;
 pub use matching_entry::MatchingEntry;
 pub use matchings::Matchings;
@@ -127,6 +127,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::mutable_key_type)]
     fn returns_some_match_if_a_matching_for_the_node_is_found() {
         let a_node = CSTNode::Terminal(Terminal {
             id: uuid::Uuid::new_v4(),
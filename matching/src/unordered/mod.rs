@@ -59,6 +59,7 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 1 },
             are_children_unordered: false,
+            are_children_aligned: false,
             identifier: Some(vec!["unique"]),
             leading_white_space: None,
             delimiters: None,
@@ -74,6 +75,7 @@ mod tests {
             start_position: Point { row: 0, column: 2 },
             end_position: Point { row: 0, column: 3 },
             are_children_unordered: false,
+            are_children_aligned: false,
             identifier: Some(vec!["dup"]),
             leading_white_space: None,
             delimiters: None,
@@ -93,6 +95,7 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 3 },
             are_children_unordered: true,
+            are_children_aligned: false,
             identifier: None,
             leading_white_space: None,
             delimiters: None,
@@ -110,6 +113,7 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 3 },
             are_children_unordered: true,
+            are_children_aligned: false,
             identifier: None,
             leading_white_space: None,
             delimiters: None,
@@ -11,8 +11,8 @@ pub fn calculate_label_matchings<'a>(
     matchings: &mut Matchings<'a>,
 ) -> (usize, Vec<&'a CSTNode<'a>>, Vec<&'a CSTNode<'a>>) {
     let left_identifier_counts: HashMap<String, usize, FxBuildHasher> =
-        identifier_counts(&left_children);
-    let right_identifier_counts = identifier_counts(&right_children);
+        identifier_counts(left_children);
+    let right_identifier_counts = identifier_counts(right_children);
     let shared_unique_identifiers =
         shared_unique_identifiers(&left_identifier_counts, &right_identifier_counts);
 
@@ -139,6 +139,7 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 10 },
             are_children_unordered: true,
+            are_children_aligned: false,
             identifier: None,
             leading_white_space: None,
             delimiters: None,
@@ -158,6 +159,7 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 1 },
             are_children_unordered: false,
+            are_children_aligned: false,
             identifier: Some(vec!["abbr"]),
             leading_white_space: None,
             delimiters: None,
@@ -171,6 +173,7 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 1 },
             are_children_unordered: true,
+            are_children_aligned: false,
             identifier: None,
             leading_white_space: None,
             delimiters: None,
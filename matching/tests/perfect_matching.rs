@@ -21,7 +21,8 @@ fn the_perfect_matching_calculation_is_correct() -> Result<(), Box<dyn std::erro
             }
         """#,
         &config,
-    )?;
+    )?
+    .root;
 
     let right = parsing::parse_string(
         r#"""
@@ -40,7 +41,8 @@ fn the_perfect_matching_calculation_is_correct() -> Result<(), Box<dyn std::erro
             }
         """#,
         &config,
-    )?;
+    )?
+    .root;
 
     let matchings = matching::calculate_matchings(&left, &right);
     assert!(
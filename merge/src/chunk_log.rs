@@ -0,0 +1,298 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::log_structures::{ChunkData, LogState, MergeChunk};
+
+/// An owned, serializable copy of the chunks recorded in a [`LogState`].
+///
+/// Unlike the log itself, it does not borrow the trees, so it can outlive the merge and be
+/// exported as JSON or as compact text, one line per chunk.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ChunkLog {
+    pub chunks: Vec<LoggedChunk>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stability {
+    Stable,
+    Unstable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LoggedChunk {
+    pub stability: Stability,
+    /// Kinds of the unordered nodes being merged around this chunk, outermost first
+    pub unordered_context: Vec<String>,
+    pub left: Vec<LoggedNode>,
+    pub base: Vec<LoggedNode>,
+    pub right: Vec<LoggedNode>,
+}
+
+/// A 1-based line and column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A half-open range of byte offsets in the revision the node comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ByteRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LoggedNode {
+    pub id: String,
+    pub kind: String,
+    pub start: Position,
+    pub end: Position,
+    pub bytes: ByteRange,
+}
+
+/// Start offsets of every line of a source, used to turn positions into byte offsets
+struct LineOffsets {
+    starts: Vec<usize>,
+    len: usize,
+}
+
+impl LineOffsets {
+    fn new(source: &str) -> Self {
+        let starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        LineOffsets {
+            starts,
+            len: source.len(),
+        }
+    }
+
+    fn byte_offset(&self, point: model::Point) -> usize {
+        self.starts
+            .get(point.row)
+            .map_or(self.len, |start| (start + point.column).min(self.len))
+    }
+}
+
+impl ChunkLog {
+    /// Copies the chunks of `log_state`. The sources of each revision are used to compute the
+    /// byte ranges of the nodes.
+    pub fn new(log_state: &LogState, base: &str, left: &str, right: &str) -> Self {
+        let (base, left, right) = (
+            LineOffsets::new(base),
+            LineOffsets::new(left),
+            LineOffsets::new(right),
+        );
+
+        let mut unordered_context = vec![];
+        let mut chunks = vec![];
+        for chunk in log_state.log.iter() {
+            let (stability, data) = match chunk {
+                MergeChunk::Stable(data) => (Stability::Stable, data),
+                MergeChunk::Unstable(data) => (Stability::Unstable, data),
+                MergeChunk::UnorderedContextStart { node_kind } => {
+                    unordered_context.push(node_kind.to_string());
+                    continue;
+                }
+                MergeChunk::UnorderedContextEnd { .. } => {
+                    unordered_context.pop();
+                    continue;
+                }
+            };
+
+            chunks.push(LoggedChunk::new(
+                stability,
+                unordered_context.clone(),
+                data,
+                [&left, &base, &right],
+            ));
+        }
+
+        ChunkLog { chunks }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Renders one line per chunk, with tab separated fields:
+    /// index, `S` or `U`, the unordered context joined by `>` (or `-`), then the left, base and
+    /// right nodes as `kind@line:column-line:column[start..end]#id`, comma separated (or `-`).
+    pub fn to_compact_text(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for ChunkLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, chunk) in self.chunks.iter().enumerate() {
+            writeln!(f, "{}\t{chunk}", index + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl LoggedChunk {
+    fn new(
+        stability: Stability,
+        unordered_context: Vec<String>,
+        data: &ChunkData,
+        [left, base, right]: [&LineOffsets; 3],
+    ) -> Self {
+        let log_nodes = |nodes: &Vec<&model::CSTNode>, offsets: &LineOffsets| {
+            nodes
+                .iter()
+                .map(|node| LoggedNode::new(node, offsets))
+                .collect()
+        };
+
+        LoggedChunk {
+            stability,
+            unordered_context,
+            left: log_nodes(&data.left_nodes, left),
+            base: log_nodes(&data.base_nodes, base),
+            right: log_nodes(&data.right_nodes, right),
+        }
+    }
+}
+
+impl LoggedNode {
+    fn new(node: &model::CSTNode, offsets: &LineOffsets) -> Self {
+        let position = |point: model::Point| Position {
+            line: point.row + 1,
+            column: point.column + 1,
+        };
+
+        LoggedNode {
+            id: node.id().to_string(),
+            kind: node.kind().to_string(),
+            start: position(node.start_position()),
+            end: position(node.end_position()),
+            bytes: ByteRange {
+                start: offsets.byte_offset(node.start_position()),
+                end: offsets.byte_offset(node.end_position()),
+            },
+        }
+    }
+}
+
+impl fmt::Display for LoggedChunk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.stability {
+            Stability::Stable => write!(f, "S\t")?,
+            Stability::Unstable => write!(f, "U\t")?,
+        }
+
+        if self.unordered_context.is_empty() {
+            write!(f, "-")?;
+        } else {
+            write!(f, "{}", self.unordered_context.join(">"))?;
+        }
+
+        for nodes in [&self.left, &self.base, &self.right] {
+            write!(f, "\t")?;
+            if nodes.is_empty() {
+                write!(f, "-")?;
+            }
+            for (index, node) in nodes.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "{node}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for LoggedNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}@{}:{}-{}:{}[{}..{}]#{}",
+            self.kind,
+            self.start.line,
+            self.start.column,
+            self.end.line,
+            self.end.column,
+            self.bytes.start,
+            self.bytes.end,
+            self.id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChunkLog, Stability};
+    use crate::log_structures::{ChunkData, LogState, MergeChunk};
+    use model::{cst_node::Terminal, CSTNode, Point};
+
+    #[test]
+    fn it_keeps_the_unordered_context_of_each_chunk() {
+        let source = "a\nbb";
+        let node = CSTNode::Terminal(Terminal {
+            kind: "identifier",
+            value: "bb",
+            start_position: Point { row: 1, column: 0 },
+            end_position: Point { row: 1, column: 2 },
+            ..Default::default()
+        });
+        let log_state = LogState {
+            log: vec![
+                MergeChunk::UnorderedContextStart {
+                    node_kind: "class_body",
+                },
+                MergeChunk::Unstable(ChunkData {
+                    left_nodes: vec![&node],
+                    ..Default::default()
+                }),
+                MergeChunk::UnorderedContextEnd {
+                    node_kind: "class_body",
+                },
+                MergeChunk::Stable(ChunkData::default()),
+            ],
+            ..Default::default()
+        };
+
+        let chunk_log = ChunkLog::new(&log_state, source, source, source);
+
+        assert_eq!(chunk_log.chunks.len(), 2);
+        assert_eq!(chunk_log.chunks[0].stability, Stability::Unstable);
+        assert_eq!(chunk_log.chunks[0].unordered_context, vec!["class_body"]);
+        assert_eq!(chunk_log.chunks[0].left[0].bytes.start, 2);
+        assert_eq!(chunk_log.chunks[0].left[0].bytes.end, 4);
+        assert!(chunk_log.chunks[1].unordered_context.is_empty());
+    }
+
+    #[test]
+    fn it_renders_one_line_per_chunk_in_compact_text() {
+        let node = CSTNode::Terminal(Terminal {
+            id: uuid::Uuid::nil(),
+            kind: "identifier",
+            value: "a",
+            start_position: Point { row: 0, column: 0 },
+            end_position: Point { row: 0, column: 1 },
+            ..Default::default()
+        });
+        let log_state = LogState {
+            log: vec![MergeChunk::Stable(ChunkData {
+                left_nodes: vec![&node],
+                base_nodes: vec![&node],
+                right_nodes: vec![],
+            })],
+            ..Default::default()
+        };
+
+        let chunk_log = ChunkLog::new(&log_state, "a", "a", "");
+
+        let node = "identifier@1:1-1:2[0..1]#00000000-0000-0000-0000-000000000000";
+        assert_eq!(
+            chunk_log.to_compact_text(),
+            format!("1\tS\t-\t{node}\t{node}\t-\n")
+        );
+    }
+}
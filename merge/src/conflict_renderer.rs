@@ -0,0 +1,155 @@
+use std::fmt::{self, Write};
+
+use crate::{
+    merged_cst_node::ConflictLocation,
+    render_options::{ConflictFavor, ConflictStyle, RenderOptions},
+    MergedCSTNode,
+};
+
+/// Writes merged trees to text. Every conflict, structural or line based, goes
+/// through here, so markers always take whole lines and follow the same options.
+pub(crate) struct ConflictRenderer<'w, 'o> {
+    output: &'w mut dyn Write,
+    options: &'o RenderOptions,
+    at_line_start: bool,
+    /// Row the next character is written to
+    row: usize,
+    /// Where each conflict rendered so far is
+    conflict_locations: Vec<ConflictLocation>,
+}
+
+impl Write for ConflictRenderer<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if let Some(last) = s.chars().last() {
+            self.at_line_start = last == '\n';
+        }
+        self.row += s.matches('\n').count();
+        self.output.write_str(s)
+    }
+}
+
+impl<'w, 'o> ConflictRenderer<'w, 'o> {
+    pub(crate) fn new(output: &'w mut dyn Write, options: &'o RenderOptions) -> Self {
+        Self {
+            output,
+            options,
+            at_line_start: true,
+            row: 0,
+            conflict_locations: vec![],
+        }
+    }
+
+    /// Locations of the conflicts rendered so far, in the order they appear in the output
+    pub(crate) fn into_conflict_locations(self) -> Vec<ConflictLocation> {
+        self.conflict_locations
+    }
+
+    pub(crate) fn render(&mut self, node: &MergedCSTNode) -> fmt::Result {
+        match node {
+            MergedCSTNode::Terminal { value, .. } => self.write_str(value),
+            MergedCSTNode::NonTerminal { children, .. } => {
+                for current in children {
+                    if let Some(leading) = current.leading_white_space() {
+                        self.write_str(leading)?
+                    }
+                    self.render(current)?;
+                }
+                Ok(())
+            }
+            MergedCSTNode::Conflict {
+                left,
+                base,
+                right,
+                record,
+            } => {
+                let index = self.conflict_locations.len();
+                self.conflict_locations.push(ConflictLocation {
+                    record: *record,
+                    rows: None,
+                });
+                match self.options.favor {
+                    Some(ConflictFavor::Ours) => self.render_side(left),
+                    Some(ConflictFavor::Theirs) => self.render_side(right),
+                    Some(ConflictFavor::Union) => {
+                        self.render_side(left)?;
+                        self.render_side(right)
+                    }
+                    None => {
+                        let start_row = self.row + usize::from(!self.at_line_start);
+                        self.render_conflict(left, base, right)?;
+                        self.conflict_locations[index].rows = Some((start_row, self.row - 1));
+                        Ok(())
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_side(&mut self, side: &Option<Box<MergedCSTNode>>) -> fmt::Result {
+        match side {
+            Some(side) => {
+                if let Some(leading) = side.leading_white_space() {
+                    self.write_str(leading)?
+                }
+                self.render(side)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn render_conflict(
+        &mut self,
+        left: &Option<Box<MergedCSTNode>>,
+        base: &Option<Box<MergedCSTNode>>,
+        right: &Option<Box<MergedCSTNode>>,
+    ) -> fmt::Result {
+        if left.is_none() && right.is_none() {
+            unreachable!("Invalid conflict provided")
+        }
+
+        let options = self.options;
+        self.write_marker(&options.start_marker())?;
+        self.render_section(left)?;
+        if options.style == ConflictStyle::Diff3 {
+            self.write_marker(&options.base_marker())?;
+            self.render_section(base)?;
+        }
+        self.write_marker(&options.separator_marker())?;
+        self.render_section(right)?;
+        self.write_marker(&options.end_marker())
+    }
+
+    fn render_section(&mut self, section: &Option<Box<MergedCSTNode>>) -> fmt::Result {
+        match section {
+            Some(section) => {
+                // Comments attached to a conflicting node belong to its section. The markers
+                // already end their line, so only the indentation of a node starting a line is
+                // kept from the white space before it.
+                if let Some(trivia) = section.leading_white_space() {
+                    self.write_str(without_leading_line_breaks(trivia))?
+                }
+                self.render(section)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn write_marker(&mut self, marker: &str) -> fmt::Result {
+        if !self.at_line_start {
+            self.write_char('\n')?;
+        }
+        self.write_str(marker)?;
+        self.write_char('\n')
+    }
+}
+
+/// `text` from the start of its first line that is not blank, or without its leading white
+/// space if it has no line break before its content
+fn without_leading_line_breaks(text: &str) -> &str {
+    let content = text.trim_start();
+    let blank = &text[..text.len() - content.len()];
+    match blank.rfind('\n') {
+        Some(last_line_break) => &text[last_line_break + 1..],
+        None => content,
+    }
+}
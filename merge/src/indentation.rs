@@ -0,0 +1,288 @@
+use std::borrow::Cow;
+
+use model::{cst_node::NonTerminal, CSTNode};
+
+use crate::MergedCSTNode;
+
+/// How the members of a container are laid out when each starts on its own line
+struct LineLayout<'a> {
+    /// Indentations found among the members, the first one is used for children with another
+    indentations: Vec<&'a str>,
+    /// Whether the first member starts right where the container does, as in Python blocks,
+    /// whose indentation is taken by the block itself
+    first_inline: bool,
+}
+
+/// Keeps the children of an unordered merge laid out like the members of `container`, for
+/// containers whose children are [aligned](NonTerminal::are_children_aligned).
+///
+/// Children may be taken from the other revision or land in a different position than the one
+/// they had, so the white space they carry may not fit. If the members of `container` each start
+/// on their own line, every child is made to start on its own line at the same indentation, and
+/// its contents are re-indented along. This is what keeps merges of indentation sensitive
+/// languages, such as Python, valid.
+pub(crate) fn realign_children<'a>(
+    container: &'a NonTerminal<'a>,
+    children: Vec<MergedCSTNode<'a>>,
+) -> Vec<MergedCSTNode<'a>> {
+    let Some(layout) = LineLayout::of(container) else {
+        return children;
+    };
+
+    children
+        .into_iter()
+        .enumerate()
+        .map(|(index, child)| layout.realign(index, child))
+        .collect()
+}
+
+impl<'a> LineLayout<'a> {
+    fn of(container: &'a NonTerminal<'a>) -> Option<Self> {
+        let members: Vec<&CSTNode> = container
+            .children
+            .iter()
+            .filter(|child| {
+                container
+                    .delimiters
+                    .map(|delimiters| !delimiters.is_delimiter(child))
+                    .unwrap_or(true)
+            })
+            .collect();
+
+        let first_inline = members
+            .first()
+            .is_some_and(|member| indentation_of(member.leading_white_space()).is_none());
+        let skipped = if first_inline { 1 } else { 0 };
+        if members
+            .iter()
+            .skip(skipped)
+            .any(|member| indentation_of(member.leading_white_space()).is_none())
+        {
+            return None;
+        }
+
+        let mut indentations: Vec<&str> = members
+            .iter()
+            .filter_map(|member| indentation_of(member.leading_white_space()))
+            .collect();
+        if first_inline {
+            indentations.extend(indentation_of(container.leading_white_space));
+        }
+        if indentations.is_empty() {
+            return None;
+        }
+
+        Some(LineLayout {
+            indentations,
+            first_inline,
+        })
+    }
+
+    fn realign<'b>(&self, index: usize, child: MergedCSTNode<'b>) -> MergedCSTNode<'b> {
+        if matches!(child, MergedCSTNode::Conflict { .. }) {
+            return child;
+        }
+
+        let white_space = child.leading_white_space().unwrap_or_default();
+        // A child with no line break takes the indentation of the container
+        let current = indentation_of(Some(white_space))
+            .unwrap_or(self.indentations[0])
+            .to_string();
+        let target = match self.indentations.contains(&current.as_str()) {
+            true => current.clone(),
+            false => self.indentations[0].to_string(),
+        };
+
+        let realigned = if index == 0 && self.first_inline {
+            reindent(white_space, &current, &target)
+                .trim_start()
+                .to_string()
+        } else if white_space.contains('\n') {
+            reindent(white_space, &current, &target)
+        } else {
+            format!("\n{}{}", target, white_space.trim_start())
+        };
+
+        if realigned == white_space && current == target {
+            return child;
+        }
+
+        let child = if current == target {
+            child
+        } else {
+            reindent_node(child, &current, &target)
+        };
+        child.with_owned_leading_white_space(Some(Cow::Owned(realigned)))
+    }
+}
+
+/// The white space after the last line break, if there is one
+fn indentation_of(white_space: Option<&str>) -> Option<&str> {
+    white_space.and_then(|white_space| {
+        white_space
+            .rfind('\n')
+            .map(|index| &white_space[index + 1..])
+    })
+}
+
+/// Swaps the `from` indentation of every line after the first for `to`
+fn reindent(white_space: &str, from: &str, to: &str) -> String {
+    let mut lines = white_space.split('\n');
+    let first_line = lines.next().unwrap_or_default().to_string();
+    lines.fold(first_line, |mut reindented, line| {
+        reindented.push('\n');
+        match line.strip_prefix(from) {
+            Some(rest) if !line.is_empty() => {
+                reindented.push_str(to);
+                reindented.push_str(rest);
+            }
+            _ => reindented.push_str(line),
+        }
+        reindented
+    })
+}
+
+fn reindent_node<'a>(node: MergedCSTNode<'a>, from: &str, to: &str) -> MergedCSTNode<'a> {
+    let reindent_white_space = |white_space: Option<Cow<'a, str>>| {
+        white_space.map(|white_space| match white_space.contains('\n') {
+            true => Cow::Owned(reindent(&white_space, from, to)),
+            false => white_space,
+        })
+    };
+
+    match node {
+        MergedCSTNode::Terminal {
+            kind,
+            value,
+            leading_white_space,
+        } => MergedCSTNode::Terminal {
+            kind,
+            value,
+            leading_white_space: reindent_white_space(leading_white_space),
+        },
+        MergedCSTNode::NonTerminal {
+            kind,
+            children,
+            leading_white_space,
+        } => MergedCSTNode::NonTerminal {
+            kind,
+            children: children
+                .into_iter()
+                .map(|child| reindent_node(child, from, to))
+                .collect(),
+            leading_white_space: reindent_white_space(leading_white_space),
+        },
+        MergedCSTNode::Conflict {
+            left,
+            base,
+            right,
+            record,
+        } => {
+            let reindent_side = |side: Option<Box<MergedCSTNode<'a>>>| {
+                side.map(|side| Box::new(reindent_node(*side, from, to)))
+            };
+            MergedCSTNode::Conflict {
+                left: reindent_side(left),
+                base: reindent_side(base),
+                right: reindent_side(right),
+                record,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::realign_children;
+    use crate::MergedCSTNode;
+
+    fn member(value: &'static str, leading_white_space: &'static str) -> CSTNode<'static> {
+        CSTNode::Terminal(Terminal {
+            kind: "member",
+            value,
+            leading_white_space: Some(leading_white_space),
+            ..Default::default()
+        })
+    }
+
+    fn block(children: Vec<CSTNode<'static>>) -> NonTerminal<'static> {
+        NonTerminal {
+            kind: "block",
+            children,
+            leading_white_space: Some("\n    "),
+            ..Default::default()
+        }
+    }
+
+    fn render(children: Vec<MergedCSTNode>) -> String {
+        MergedCSTNode::NonTerminal {
+            kind: "block",
+            children,
+            leading_white_space: None,
+        }
+        .to_string()
+    }
+
+    #[test]
+    fn it_breaks_the_line_before_a_first_member_moved_further_down() {
+        let container = block(vec![member("a", ""), member("b", "\n    ")]);
+        let b = member("b", "\n    ");
+        let a = member("a", "");
+
+        let realigned = realign_children(&container, vec![(&b).into(), (&a).into()]);
+
+        assert_eq!(render(realigned), "b\n    a");
+    }
+
+    #[test]
+    fn it_reindents_members_taken_from_a_differently_indented_revision() {
+        let container = block(vec![member("a", ""), member("b", "\n    ")]);
+        let nested = CSTNode::NonTerminal(NonTerminal {
+            kind: "function_definition",
+            children: vec![member("def c():", ""), member("pass", "\n    ")],
+            leading_white_space: Some("\n  "),
+            ..Default::default()
+        });
+
+        let realigned = realign_children(
+            &container,
+            vec![(&container.children[0]).into(), (&nested).into()],
+        );
+
+        assert_eq!(render(realigned), "a\n    def c():\n      pass");
+    }
+
+    #[test]
+    fn it_leaves_members_sharing_a_line_alone() {
+        let container = NonTerminal {
+            kind: "object",
+            children: vec![member("a", " "), member("b", " ")],
+            ..Default::default()
+        };
+        let children: Vec<MergedCSTNode> = container
+            .children
+            .iter()
+            .rev()
+            .map(|child| child.into())
+            .collect();
+
+        let realigned = realign_children(&container, children.clone());
+
+        assert_eq!(realigned, children);
+        assert_eq!(
+            realigned[0],
+            MergedCSTNode::Terminal {
+                kind: "member",
+                value: Cow::Borrowed("b"),
+                leading_white_space: Some(Cow::Borrowed(" ")),
+            }
+        );
+    }
+}
@@ -1,12 +1,20 @@
+mod chunk_log;
+mod conflict_renderer;
+mod indentation;
+mod line_based_merge;
 mod merge;
 mod merge_error;
 mod merge_terminals;
 mod merged_cst_node;
 mod ordered_merge;
+mod render_options;
 mod unordered_merge;
 
 pub mod log_structures;
 
+pub use chunk_log::{ByteRange, ChunkLog, LoggedChunk, LoggedNode, Position, Stability};
+pub use line_based_merge::textual_merge;
 pub use merge::merge;
 pub use merge_error::MergeError;
-pub use merged_cst_node::MergedCSTNode;
+pub use merged_cst_node::{ConflictLocation, MergedCSTNode};
+pub use render_options::{ConflictFavor, ConflictStyle, RenderOptions, DEFAULT_MARKER_SIZE};
@@ -0,0 +1,183 @@
+use std::borrow::Cow;
+
+use crate::MergedCSTNode;
+
+const MARKER_CHARACTERS: [char; 4] = ['<', '|', '=', '>'];
+
+/// Plain line-based three-way merge of whole files, used when structured merge is not suitable.
+pub fn textual_merge<'a>(base: &str, left: &str, right: &str) -> MergedCSTNode<'a> {
+    line_based_merge("source_file", base, left, right, None)
+}
+
+/// Merges three texts line by line and returns each conflicting hunk as a
+/// `MergedCSTNode::Conflict`, so it is rendered like any structural conflict.
+/// Returns a single terminal if the texts merge cleanly.
+pub(crate) fn line_based_merge<'a>(
+    kind: &'a str,
+    base: &str,
+    left: &str,
+    right: &str,
+    leading_white_space: Option<&'a str>,
+) -> MergedCSTNode<'a> {
+    // diffy only reports conflicts embedded in the merged text, so the markers must not
+    // be confused with the contents: they are made longer than any run of marker
+    // characters present in the inputs.
+    let marker_size = [base, left, right]
+        .into_iter()
+        .map(longest_marker_run)
+        .max()
+        .unwrap_or_default()
+        .max(crate::DEFAULT_MARKER_SIZE - 1)
+        + 1;
+
+    let mut options = diffy::MergeOptions::new();
+    options
+        .set_conflict_marker_length(marker_size)
+        .set_conflict_style(diffy::ConflictStyle::Diff3);
+
+    match options.merge(base, left, right) {
+        Ok(value) => MergedCSTNode::Terminal {
+            kind,
+            value: Cow::Owned(value),
+            leading_white_space: leading_white_space.map(Cow::Borrowed),
+        },
+        Err(value) => MergedCSTNode::NonTerminal {
+            kind,
+            children: split_conflicts(kind, &value, marker_size),
+            leading_white_space: leading_white_space.map(Cow::Borrowed),
+        },
+    }
+}
+
+fn longest_marker_run(text: &str) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    let mut previous = None;
+    for character in text.chars() {
+        if MARKER_CHARACTERS.contains(&character) && previous == Some(character) {
+            current += 1;
+        } else if MARKER_CHARACTERS.contains(&character) {
+            current = 1;
+        } else {
+            current = 0;
+        }
+        previous = Some(character);
+        longest = longest.max(current);
+    }
+    longest
+}
+
+fn split_conflicts<'a>(kind: &'a str, merged: &str, marker_size: usize) -> Vec<MergedCSTNode<'a>> {
+    let start_marker = format!("{} ours\n", "<".repeat(marker_size));
+    let base_marker = format!("{} original\n", "|".repeat(marker_size));
+    let separator_marker = format!("{}\n", "=".repeat(marker_size));
+    let end_marker = format!("{} theirs\n", ">".repeat(marker_size));
+
+    let terminal = |value: &str| MergedCSTNode::Terminal {
+        kind,
+        value: Cow::Owned(value.to_string()),
+        leading_white_space: None,
+    };
+
+    let mut children = Vec::new();
+    let mut remaining = merged;
+    while let Some(start) = remaining.find(&start_marker) {
+        if start > 0 {
+            children.push(terminal(&remaining[..start]));
+        }
+        remaining = &remaining[start + start_marker.len()..];
+
+        let (left, rest) = split_at_marker(remaining, &base_marker);
+        let (base, rest) = split_at_marker(rest, &separator_marker);
+        let (right, rest) = split_at_marker(rest, &end_marker);
+        children.push(MergedCSTNode::Conflict {
+            left: Some(Box::new(terminal(left))),
+            base: Some(Box::new(terminal(base))),
+            right: Some(Box::new(terminal(right))),
+            record: None,
+        });
+        remaining = rest;
+    }
+    if !remaining.is_empty() {
+        children.push(terminal(remaining));
+    }
+    children
+}
+
+fn split_at_marker<'s>(text: &'s str, marker: &str) -> (&'s str, &'s str) {
+    let position = text
+        .find(marker)
+        .expect("diffy always emits every marker of a conflict");
+    (&text[..position], &text[position + marker.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::line_based_merge;
+    use crate::MergedCSTNode;
+
+    fn terminal(value: &str) -> MergedCSTNode<'_> {
+        MergedCSTNode::Terminal {
+            kind: "kind",
+            value: Cow::Owned(value.to_string()),
+            leading_white_space: None,
+        }
+    }
+
+    #[test]
+    fn it_splits_conflicting_hunks_into_conflict_nodes() {
+        let result = line_based_merge(
+            "kind",
+            "a\nb\nc\n",
+            "a\nleft\nc\n",
+            "a\nright\nc\n",
+            Some(" "),
+        );
+
+        assert_eq!(
+            result,
+            MergedCSTNode::NonTerminal {
+                kind: "kind",
+                children: vec![
+                    terminal("a\n"),
+                    MergedCSTNode::Conflict {
+                        left: Some(Box::new(terminal("left\n"))),
+                        base: Some(Box::new(terminal("b\n"))),
+                        right: Some(Box::new(terminal("right\n"))),
+                        record: None,
+                    },
+                    terminal("c\n"),
+                ],
+                leading_white_space: Some(" ".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn it_is_not_confused_by_inputs_containing_conflict_markers() {
+        let base = "<<<<<<< ours\nvalue\n";
+        let left = "<<<<<<< ours\nleft\n";
+        let right = "<<<<<<< ours\nright\n";
+
+        let result = line_based_merge("kind", base, left, right, None);
+
+        assert_eq!(
+            result,
+            MergedCSTNode::NonTerminal {
+                kind: "kind",
+                children: vec![
+                    terminal("<<<<<<< ours\n"),
+                    MergedCSTNode::Conflict {
+                        left: Some(Box::new(terminal("left\n"))),
+                        base: Some(Box::new(terminal("value\n"))),
+                        right: Some(Box::new(terminal("right\n"))),
+                        record: None,
+                    },
+                ],
+                leading_white_space: None,
+            }
+        );
+    }
+}
@@ -3,6 +3,17 @@ pub struct LogState<'a> {
     pub log: Vec<MergeChunk<'a>>,
     pub current_stable: ChunkData<'a>,
     pub current_unstable: ChunkData<'a>,
+    /// Every conflict raised, in the order they were raised. Each conflict in the merged tree
+    /// keeps the index of its record.
+    pub conflicts: Vec<ConflictRecord<'a>>,
+}
+
+impl<'a> LogState<'a> {
+    /// Adds `record` to the conflicts raised, returning its index
+    pub(crate) fn record_conflict(&mut self, record: ConflictRecord<'a>) -> usize {
+        self.conflicts.push(record);
+        self.conflicts.len() - 1
+    }
 }
 
 #[derive(Debug)]
@@ -25,3 +36,33 @@ impl<'a> ChunkData<'a> {
         self.left_nodes.is_empty() && self.base_nodes.is_empty() && self.right_nodes.is_empty()
     }
 }
+
+/// The step of the merge that raised a conflict
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictSource {
+    OrderedMerge,
+    UnorderedMerge,
+    TerminalMerge,
+    DifferentKinds,
+    LeadingTrivia,
+    /// Entries added by each side with the same identifier, such as two JSON pairs with the same
+    /// key
+    DuplicateIdentifier,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ConflictRecord<'a> {
+    pub source: ConflictSource,
+    pub left: Option<&'a model::CSTNode<'a>>,
+    pub base: Option<&'a model::CSTNode<'a>>,
+    pub right: Option<&'a model::CSTNode<'a>>,
+}
+
+impl<'a> ConflictRecord<'a> {
+    pub fn node_kind(&self) -> Option<&'a str> {
+        self.left
+            .or(self.right)
+            .or(self.base)
+            .map(|node| node.kind())
+    }
+}
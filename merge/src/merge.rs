@@ -1,11 +1,12 @@
+use crate::line_based_merge::line_based_merge;
 use crate::merge_error::MergeError;
 use crate::merge_terminals::merge_terminals;
 use crate::ordered_merge::ordered_merge;
 use crate::unordered_merge::unordered_merge;
-use matching::Matchings;
+use matching::{Matching, Matchings};
 use model::CSTNode;
 
-use crate::log_structures::{LogState, MergeChunk};
+use crate::log_structures::{ConflictRecord, ConflictSource, LogState, MergeChunk};
 use crate::merged_cst_node::MergedCSTNode;
 
 pub fn merge<'a>(
@@ -17,23 +18,41 @@ pub fn merge<'a>(
     left_right_matchings: &'a Matchings<'a>,
     log_state: &mut Option<LogState<'a>>,
 ) -> Result<MergedCSTNode<'a>, MergeError> {
+    // When there is no base version, callers provide one of the sides in its place
+    let has_base = base.id() != left.id() && base.id() != right.id();
+
     if left.kind() != right.kind() {
         log::warn!(
             "Tried to merge nodes of different kinds\n left: {}\n right:{}. Falling back to conflict",
             left.contents(),
             right.contents()
         );
-        return Ok(MergedCSTNode::Conflict {
-            left: Some(Box::new(left.into())),
-            right: Some(Box::new(right.into())),
-        });
+        return Ok(MergedCSTNode::conflict(
+            ConflictSource::DifferentKinds,
+            Some(left),
+            has_base.then_some(base),
+            Some(right),
+            log_state,
+        ));
     }
 
-    match (base, left, right) {
+    let merged = match (base, left, right) {
         (CSTNode::Terminal(a_base), CSTNode::Terminal(a_left), CSTNode::Terminal(a_right)) => {
-            merge_terminals(a_base, a_left, a_right)
+            merge_terminals(a_base, a_left, a_right)?.with_recorded_conflicts(
+                ConflictRecord {
+                    source: ConflictSource::TerminalMerge,
+                    left: Some(left),
+                    base: has_base.then_some(base),
+                    right: Some(right),
+                },
+                log_state,
+            )
         }
-        (CSTNode::NonTerminal(_), CSTNode::NonTerminal(a_left), CSTNode::NonTerminal(a_right)) => {
+        (
+            CSTNode::NonTerminal(a_base),
+            CSTNode::NonTerminal(a_left),
+            CSTNode::NonTerminal(a_right),
+        ) => {
             if a_left.are_children_unordered && a_right.are_children_unordered {
                 if let Some(ls) = log_state.as_mut() {
                     ls.log.push(MergeChunk::UnorderedContextStart {
@@ -56,16 +75,17 @@ pub fn merge<'a>(
                     });
                 }
 
-                Ok(result)
+                result
             } else {
-                Ok(ordered_merge(
+                ordered_merge(
+                    has_base.then_some(a_base),
                     a_left,
                     a_right,
                     base_left_matchings,
                     base_right_matchings,
                     left_right_matchings,
                     log_state,
-                )?)
+                )?
             }
         }
         (_, _, _) => {
@@ -74,7 +94,94 @@ pub fn merge<'a>(
                 left.contents(),
                 right.contents()
             );
-            Err(MergeError::MergingTerminalWithNonTerminal)
+            return Err(MergeError::MergingTerminalWithNonTerminal);
+        }
+    };
+
+    Ok(merge_leading_trivia(base, left, right, merged, log_state))
+}
+
+/// Whether `node` is left as it was in the base it is matched to. Matching ignores trivia, so the
+/// comments in both are compared as well: a changed comment is a change like any other, and must
+/// not be lost when the other side deletes the node.
+pub(crate) fn is_unchanged(matching: &Matching, node: &CSTNode) -> bool {
+    matching.is_perfect_match && comments(matching.matching_node) == comments(node)
+}
+
+/// The non-blank lines of the trivia in `node`, trimmed so that reindenting them is no change
+fn comments<'a>(node: &'a CSTNode<'a>) -> Vec<&'a str> {
+    let mut lines: Vec<&str> = node
+        .leading_white_space()
+        .into_iter()
+        .flat_map(str::lines)
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    if let CSTNode::NonTerminal(non_terminal) = node {
+        lines.extend(non_terminal.children.iter().flat_map(comments));
+    }
+    lines
+}
+
+/// Leading white space may carry trivia, such as comments. Once it does, it is merged like any
+/// other content: changes from a single side are taken and changes from both sides are merged
+/// line by line, which may yield a conflict placed right before the merged node.
+fn merge_leading_trivia<'a>(
+    base: &'a CSTNode<'a>,
+    left: &'a CSTNode<'a>,
+    right: &'a CSTNode<'a>,
+    merged: MergedCSTNode<'a>,
+    log_state: &mut Option<LogState<'a>>,
+) -> MergedCSTNode<'a> {
+    let left_trivia = left.leading_white_space();
+    let right_trivia = right.leading_white_space();
+    let is_blank = |trivia: Option<&str>| trivia.is_none_or(|trivia| trivia.trim().is_empty());
+
+    // When there is no base version, callers provide one of the sides in its place
+    let has_base = base.id() != left.id() && base.id() != right.id();
+    let base_trivia = if has_base {
+        base.leading_white_space()
+    } else {
+        // Without a base, a side with only white space is taken as unchanged
+        [left_trivia, right_trivia]
+            .into_iter()
+            .find(|trivia| is_blank(*trivia))
+            .flatten()
+    };
+
+    if [base_trivia, left_trivia, right_trivia]
+        .into_iter()
+        .all(is_blank)
+    {
+        return merged;
+    }
+
+    if left_trivia == right_trivia || right_trivia == base_trivia {
+        merged.with_leading_white_space(left_trivia)
+    } else if left_trivia == base_trivia {
+        merged.with_leading_white_space(right_trivia)
+    } else {
+        let merged_trivia = line_based_merge(
+            "leading_trivia",
+            base_trivia.unwrap_or_default(),
+            left_trivia.unwrap_or_default(),
+            right_trivia.unwrap_or_default(),
+            None,
+        )
+        .with_recorded_conflicts(
+            ConflictRecord {
+                source: ConflictSource::LeadingTrivia,
+                left: Some(left),
+                base: has_base.then_some(base),
+                right: Some(right),
+            },
+            log_state,
+        );
+
+        MergedCSTNode::NonTerminal {
+            kind: left.kind(),
+            children: vec![merged_trivia, merged.with_leading_white_space(None)],
+            leading_white_space: None,
         }
     }
 }
@@ -82,6 +189,7 @@ pub fn merge<'a>(
 #[cfg(test)]
 mod tests {
     use super::merge;
+    use crate::log_structures::{ConflictSource, LogState};
     use crate::MergeError;
     use matching::Matchings;
     use model::{
@@ -130,4 +238,76 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn it_merges_leading_comments_changed_in_both_sides() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let terminal = |leading_white_space| {
+            CSTNode::Terminal(Terminal {
+                id: uuid::Uuid::new_v4(),
+                kind: "kind",
+                value: "value",
+                leading_white_space: Some(leading_white_space),
+                ..Default::default()
+            })
+        };
+        let base = terminal("\n// base comment\n");
+        let left = terminal("\n// left comment\n");
+        let right = terminal("\n// right comment\n");
+        let matchings = Matchings::empty();
+        let mut log_state = None;
+
+        let merged = merge(
+            &base,
+            &left,
+            &right,
+            &matchings,
+            &matchings,
+            &matchings,
+            &mut log_state,
+        )?;
+
+        assert!(merged.has_conflict());
+        assert_eq!(
+            merged.to_string(),
+            "\n<<<<<<<\n// left comment\n=======\n// right comment\n>>>>>>>\nvalue"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_records_the_nodes_of_each_conflict() -> Result<(), Box<dyn std::error::Error>> {
+        let terminal = |value| {
+            CSTNode::Terminal(Terminal {
+                id: uuid::Uuid::new_v4(),
+                kind: "kind",
+                value,
+                ..Default::default()
+            })
+        };
+        let base = terminal("base");
+        let left = terminal("left");
+        let right = terminal("right");
+        let matchings = Matchings::empty();
+        let mut log_state = Some(LogState::default());
+
+        merge(
+            &base,
+            &left,
+            &right,
+            &matchings,
+            &matchings,
+            &matchings,
+            &mut log_state,
+        )?;
+
+        let conflicts = log_state.unwrap().conflicts;
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].source, ConflictSource::TerminalMerge);
+        assert_eq!(conflicts[0].node_kind(), Some("kind"));
+        assert_eq!(conflicts[0].base.map(|node| node.id()), Some(base.id()));
+
+        Ok(())
+    }
 }
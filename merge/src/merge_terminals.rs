@@ -1,6 +1,6 @@
 use model::cst_node::Terminal;
 
-use crate::{MergeError, MergedCSTNode};
+use crate::{line_based_merge::line_based_merge, MergeError, MergedCSTNode};
 
 pub fn merge_terminals<'a>(
     base: &'a Terminal<'a>,
@@ -27,18 +27,13 @@ pub fn merge_terminals<'a>(
     // Changed in both
     } else if !left_equals_base && !right_equals_base {
         log::trace!("Changed in both");
-        match diffy::merge(base.value, left.value, right.value) {
-            Ok(value) => Ok(MergedCSTNode::Terminal {
-                kind: base.kind,
-                value: std::borrow::Cow::Owned(value),
-                leading_white_space: base.leading_white_space,
-            }),
-            Err(value) => Ok(MergedCSTNode::Terminal {
-                kind: base.kind,
-                value: std::borrow::Cow::Owned(value),
-                leading_white_space: base.leading_white_space,
-            }),
-        }
+        Ok(line_based_merge(
+            base.kind,
+            base.value,
+            left.value,
+            right.value,
+            base.leading_white_space,
+        ))
     // Only left changed
     } else if right_equals_base {
         log::trace!("Only left changed");
@@ -158,14 +153,37 @@ mod tests {
             ..Default::default()
         };
 
+        let merged_tree = merge_terminals(&base, &left, &right)?;
+
         assert_eq!(
-            merge_terminals(&base, &left, &right)?,
-           MergedCSTNode::Terminal {
+            merged_tree,
+            MergedCSTNode::NonTerminal {
                 kind: "kind",
-                value: std::borrow::Cow::Borrowed("<<<<<<< ours\nleft_value||||||| original\nvalue=======\nright_value>>>>>>> theirs\n"),
-                leading_white_space: None
+                children: vec![MergedCSTNode::Conflict {
+                    left: Some(Box::new(MergedCSTNode::Terminal {
+                        kind: "kind",
+                        value: std::borrow::Cow::Borrowed("left_value"),
+                        leading_white_space: None,
+                    })),
+                    base: Some(Box::new(MergedCSTNode::Terminal {
+                        kind: "kind",
+                        value: std::borrow::Cow::Borrowed("value"),
+                        leading_white_space: None,
+                    })),
+                    right: Some(Box::new(MergedCSTNode::Terminal {
+                        kind: "kind",
+                        value: std::borrow::Cow::Borrowed("right_value"),
+                        leading_white_space: None,
+                    })),
+                    record: None,
+                }],
+                leading_white_space: None,
             }
         );
+        assert_eq!(
+            merged_tree.to_string(),
+            "<<<<<<<\nleft_value\n=======\nright_value\n>>>>>>>\n"
+        );
 
         Ok(())
     }
@@ -1,28 +1,47 @@
-use std::fmt::Display;
+use std::{borrow::Cow, fmt::Display};
 
 use model::{
     cst_node::{NonTerminal, Terminal},
     CSTNode,
 };
 
+use crate::{
+    conflict_renderer::ConflictRenderer,
+    log_structures::{ConflictRecord, ConflictSource, LogState},
+    render_options::RenderOptions,
+};
+
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
 pub enum MergedCSTNode<'a> {
     Terminal {
         kind: &'a str,
-        value: std::borrow::Cow<'a, str>,
-        leading_white_space: Option<&'a str>,
+        value: Cow<'a, str>,
+        leading_white_space: Option<Cow<'a, str>>,
     },
     NonTerminal {
         kind: &'a str,
         children: Vec<MergedCSTNode<'a>>,
-        leading_white_space: Option<&'a str>,
+        leading_white_space: Option<Cow<'a, str>>,
     },
     Conflict {
         left: Option<Box<MergedCSTNode<'a>>>,
+        base: Option<Box<MergedCSTNode<'a>>>,
         right: Option<Box<MergedCSTNode<'a>>>,
+        /// Index of the conflict in the conflicts of the merge log, if it was recorded there
+        record: Option<usize>,
     },
 }
 
+/// Where a conflict ended up in a rendered tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConflictLocation {
+    /// Index of the conflict in the conflicts of the merge log, if it was recorded there
+    pub record: Option<usize>,
+    /// First and last rows of the conflict, markers included, or `None` if it was resolved in
+    /// favor of a side
+    pub rows: Option<(usize, usize)>,
+}
+
 impl<'a> From<&'a CSTNode<'a>> for MergedCSTNode<'a> {
     fn from(val: &'a CSTNode<'a>) -> Self {
         match val {
@@ -35,7 +54,7 @@ impl<'a> From<&'a CSTNode<'a>> for MergedCSTNode<'a> {
             }) => MergedCSTNode::NonTerminal {
                 kind,
                 children: children.iter().map(|node| node.into()).collect(),
-                leading_white_space: *leading_white_space,
+                leading_white_space: leading_white_space.map(Cow::Borrowed),
             },
         }
     }
@@ -45,55 +64,98 @@ impl<'a> From<&'a Terminal<'a>> for MergedCSTNode<'a> {
     fn from(val: &'a Terminal<'a>) -> Self {
         MergedCSTNode::Terminal {
             kind: val.kind,
-            value: std::borrow::Cow::Borrowed(val.value),
-            leading_white_space: val.leading_white_space,
+            value: Cow::Borrowed(val.value),
+            leading_white_space: val.leading_white_space.map(Cow::Borrowed),
         }
     }
 }
 
 impl Display for MergedCSTNode<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        ConflictRenderer::new(f, &RenderOptions::default()).render(self)
+    }
+}
+
+impl<'a> MergedCSTNode<'a> {
+    /// Builds a conflict between the given nodes and records where it was raised
+    pub(crate) fn conflict(
+        source: ConflictSource,
+        left: Option<&'a CSTNode<'a>>,
+        base: Option<&'a CSTNode<'a>>,
+        right: Option<&'a CSTNode<'a>>,
+        log_state: &mut Option<LogState<'a>>,
+    ) -> Self {
+        let record = log_state.as_mut().map(|ls| {
+            ls.record_conflict(ConflictRecord {
+                source,
+                left,
+                base,
+                right,
+            })
+        });
+
+        MergedCSTNode::Conflict {
+            left: left.map(|node| Box::new(node.into())),
+            base: base.map(|node| Box::new(node.into())),
+            right: right.map(|node| Box::new(node.into())),
+            record,
+        }
+    }
+
+    /// Records the conflicts in the tree that are not recorded yet, such as the ones of a line
+    /// based merge, as raised by `record`
+    pub(crate) fn with_recorded_conflicts(
+        self,
+        record: ConflictRecord<'a>,
+        log_state: &mut Option<LogState<'a>>,
+    ) -> Self {
         match self {
-            MergedCSTNode::Terminal { value, .. } => write!(f, "{value}"),
-            MergedCSTNode::NonTerminal { children, .. } => {
-                for current in children {
-                    if let Some(leading) = current.leading_white_space() {
-                        write!(f, "{leading}")?
-                    }
-                    write!(f, "{current}")?;
-                }
-                Ok(())
-            }
-            MergedCSTNode::Conflict { left, right } => match (left, right) {
-                (Some(left), Some(right)) => {
-                    writeln!(f)?;
-                    writeln!(f, "<<<<<<<")?;
-                    writeln!(f, "{left}")?;
-                    writeln!(f, "=======")?;
-                    writeln!(f, "{right}")?;
-                    writeln!(f, ">>>>>>>")
-                }
-                (Some(left), None) => {
-                    writeln!(f)?;
-                    writeln!(f, "<<<<<<<")?;
-                    writeln!(f, "{left}")?;
-                    writeln!(f, "=======")?;
-                    writeln!(f, ">>>>>>>")
-                }
-                (None, Some(right)) => {
-                    writeln!(f)?;
-                    writeln!(f, "<<<<<<<")?;
-                    writeln!(f, "=======")?;
-                    writeln!(f, "{right}")?;
-                    writeln!(f, ">>>>>>>")
-                }
-                (None, None) => unreachable!("Invalid conflict provided"),
+            MergedCSTNode::NonTerminal {
+                kind,
+                children,
+                leading_white_space,
+            } => MergedCSTNode::NonTerminal {
+                kind,
+                children: children
+                    .into_iter()
+                    .map(|child| child.with_recorded_conflicts(record, log_state))
+                    .collect(),
+                leading_white_space,
+            },
+            MergedCSTNode::Conflict {
+                left,
+                base,
+                right,
+                record: None,
+            } => MergedCSTNode::Conflict {
+                left,
+                base,
+                right,
+                record: log_state.as_mut().map(|ls| ls.record_conflict(record)),
             },
+            node => node,
         }
     }
-}
 
-impl MergedCSTNode<'_> {
+    pub fn render(&self, options: &RenderOptions) -> String {
+        self.render_locating_conflicts(options).0
+    }
+
+    /// Renders the tree along with the location of each conflict in it, in the order they appear
+    /// in the output
+    pub fn render_locating_conflicts(
+        &self,
+        options: &RenderOptions,
+    ) -> (String, Vec<ConflictLocation>) {
+        let mut output = String::new();
+        let mut renderer = ConflictRenderer::new(&mut output, options);
+        renderer
+            .render(self)
+            .expect("Writing to a String never fails");
+        let conflict_locations = renderer.into_conflict_locations();
+        (output, conflict_locations)
+    }
+
     pub fn has_conflict(&self) -> bool {
         match self {
             MergedCSTNode::NonTerminal { children, .. } => {
@@ -104,17 +166,248 @@ impl MergedCSTNode<'_> {
         }
     }
 
+    pub fn conflict_count(&self) -> usize {
+        match self {
+            MergedCSTNode::NonTerminal { children, .. } => {
+                children.iter().map(|child| child.conflict_count()).sum()
+            }
+            MergedCSTNode::Terminal { .. } => 0,
+            MergedCSTNode::Conflict { .. } => 1,
+        }
+    }
+
+    pub(crate) fn with_leading_white_space(self, leading_white_space: Option<&'a str>) -> Self {
+        self.with_owned_leading_white_space(leading_white_space.map(Cow::Borrowed))
+    }
+
+    pub(crate) fn with_owned_leading_white_space(
+        self,
+        leading_white_space: Option<Cow<'a, str>>,
+    ) -> Self {
+        match self {
+            MergedCSTNode::Terminal { kind, value, .. } => MergedCSTNode::Terminal {
+                kind,
+                value,
+                leading_white_space,
+            },
+            MergedCSTNode::NonTerminal { kind, children, .. } => MergedCSTNode::NonTerminal {
+                kind,
+                children,
+                leading_white_space,
+            },
+            conflict @ MergedCSTNode::Conflict { .. } => conflict,
+        }
+    }
+
     pub fn leading_white_space(&self) -> Option<&str> {
         match self {
             MergedCSTNode::Terminal {
                 leading_white_space,
                 ..
-            } => *leading_white_space,
+            } => leading_white_space.as_deref(),
             MergedCSTNode::NonTerminal {
                 leading_white_space,
                 ..
-            } => *leading_white_space,
+            } => leading_white_space.as_deref(),
             MergedCSTNode::Conflict { .. } => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{ConflictFavor, ConflictLocation, ConflictStyle, MergedCSTNode, RenderOptions};
+
+    fn terminal<'a>(value: &'a str, leading_white_space: Option<&'a str>) -> MergedCSTNode<'a> {
+        MergedCSTNode::Terminal {
+            kind: "kind",
+            value: Cow::Borrowed(value),
+            leading_white_space: leading_white_space.map(Cow::Borrowed),
+        }
+    }
+
+    fn conflicting_tree() -> MergedCSTNode<'static> {
+        conflicting_tree_recorded_as(None)
+    }
+
+    fn conflicting_tree_recorded_as(record: Option<usize>) -> MergedCSTNode<'static> {
+        MergedCSTNode::NonTerminal {
+            kind: "kind",
+            children: vec![
+                terminal("int x =", None),
+                MergedCSTNode::Conflict {
+                    left: Some(Box::new(terminal("3", Some(" ")))),
+                    base: Some(Box::new(terminal("4", Some(" ")))),
+                    right: Some(Box::new(terminal("5", Some(" ")))),
+                    record,
+                },
+                terminal(";", None),
+            ],
+            leading_white_space: None,
+        }
+    }
+
+    #[test]
+    fn it_renders_conflicts_with_bare_markers_by_default() {
+        assert_eq!(
+            conflicting_tree().to_string(),
+            "int x =\n<<<<<<<\n3\n=======\n5\n>>>>>>>\n;"
+        );
+    }
+
+    #[test]
+    fn it_renders_conflicts_with_the_provided_labels_and_marker_size() {
+        let options = RenderOptions {
+            left_label: Some("left.java".into()),
+            right_label: Some("right.java".into()),
+            marker_size: 4,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            conflicting_tree().render(&options),
+            "int x =\n<<<< left.java\n3\n====\n5\n>>>> right.java\n;"
+        );
+    }
+
+    #[test]
+    fn it_renders_the_base_version_when_diff3_style_is_requested() {
+        let options = RenderOptions {
+            base_label: Some("base.java".into()),
+            style: ConflictStyle::Diff3,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            conflicting_tree().render(&options),
+            "int x =\n<<<<<<<\n3\n||||||| base.java\n4\n=======\n5\n>>>>>>>\n;"
+        );
+    }
+
+    #[test]
+    fn it_renders_an_empty_base_section_when_the_base_version_is_missing() {
+        let tree = MergedCSTNode::Conflict {
+            left: Some(Box::new(terminal("3", None))),
+            base: None,
+            right: Some(Box::new(terminal("5", None))),
+            record: None,
+        };
+        let options = RenderOptions {
+            style: ConflictStyle::Diff3,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            tree.render(&options),
+            "<<<<<<<\n3\n|||||||\n=======\n5\n>>>>>>>\n"
+        );
+    }
+
+    #[test]
+    fn it_does_not_add_line_breaks_around_markers_when_the_text_already_has_them() {
+        let tree = MergedCSTNode::NonTerminal {
+            kind: "kind",
+            children: vec![
+                terminal("a\n", None),
+                MergedCSTNode::Conflict {
+                    left: Some(Box::new(terminal("left\n", None))),
+                    base: Some(Box::new(terminal("b\n", None))),
+                    right: Some(Box::new(terminal("right\n", None))),
+                    record: None,
+                },
+                terminal("c\n", None),
+            ],
+            leading_white_space: None,
+        };
+
+        assert_eq!(
+            tree.to_string(),
+            "a\n<<<<<<<\nleft\n=======\nright\n>>>>>>>\nc\n"
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_indentation_of_conflicting_nodes_that_start_a_line() {
+        let tree = MergedCSTNode::NonTerminal {
+            kind: "kind",
+            children: vec![
+                terminal("class A {", None),
+                MergedCSTNode::Conflict {
+                    left: Some(Box::new(terminal("int x;", Some("\n\n    ")))),
+                    base: None,
+                    right: Some(Box::new(terminal("// y\n    int y;", Some("\n    ")))),
+                    record: None,
+                },
+                terminal("}", None),
+            ],
+            leading_white_space: None,
+        };
+
+        assert_eq!(
+            tree.to_string(),
+            "class A {\n<<<<<<<\n    int x;\n=======\n    // y\n    int y;\n>>>>>>>\n}"
+        );
+    }
+
+    #[test]
+    fn it_counts_conflicts_in_the_whole_tree() {
+        let tree = MergedCSTNode::NonTerminal {
+            kind: "kind",
+            children: vec![conflicting_tree(), terminal(";", None), conflicting_tree()],
+            leading_white_space: None,
+        };
+
+        assert_eq!(tree.conflict_count(), 2);
+        assert_eq!(terminal(";", None).conflict_count(), 0);
+    }
+
+    #[test]
+    fn it_resolves_conflicts_according_to_the_favored_side() {
+        let render_favoring = |favor| {
+            conflicting_tree().render(&RenderOptions {
+                favor: Some(favor),
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(render_favoring(ConflictFavor::Ours), "int x = 3;");
+        assert_eq!(render_favoring(ConflictFavor::Theirs), "int x = 5;");
+        assert_eq!(render_favoring(ConflictFavor::Union), "int x = 3 5;");
+    }
+
+    #[test]
+    fn it_locates_each_conflict_where_it_is_rendered() {
+        let tree = MergedCSTNode::NonTerminal {
+            kind: "kind",
+            children: vec![
+                conflicting_tree_recorded_as(Some(1)),
+                terminal("\n<<<<<<< not a marker\n", None),
+                conflicting_tree_recorded_as(Some(0)),
+            ],
+            leading_white_space: None,
+        };
+
+        let (_, locations) = tree.render_locating_conflicts(&RenderOptions::default());
+        assert_eq!(
+            locations,
+            [
+                ConflictLocation {
+                    record: Some(1),
+                    rows: Some((1, 5))
+                },
+                ConflictLocation {
+                    record: Some(0),
+                    rows: Some((9, 13))
+                }
+            ]
+        );
+
+        let (_, locations) = tree.render_locating_conflicts(&RenderOptions {
+            favor: Some(ConflictFavor::Ours),
+            ..Default::default()
+        });
+        assert!(locations.iter().all(|location| location.rows.is_none()));
+    }
+}
@@ -1,10 +1,12 @@
 use matching::Matchings;
-use model::cst_node::NonTerminal;
+use model::{cst_node::NonTerminal, CSTNode};
 
-use crate::log_structures::{LogState, MergeChunk};
+use crate::log_structures::{ConflictSource, LogState, MergeChunk};
+use crate::merge::is_unchanged;
 use crate::{MergeError, MergedCSTNode};
 
 pub fn ordered_merge<'a>(
+    base: Option<&'a NonTerminal<'a>>,
     left: &'a NonTerminal<'a>,
     right: &'a NonTerminal<'a>,
     base_left_matchings: &'a Matchings<'a>,
@@ -186,11 +188,14 @@ pub fn ordered_merge<'a>(
                     ls.current_unstable.right_nodes.push(cur_right);
                 }
 
-                if !matching_base_right.is_perfect_match {
-                    result_children.push(MergedCSTNode::Conflict {
-                        left: None,
-                        right: Some(Box::new(cur_right.into())),
-                    });
+                if !is_unchanged(&matching_base_right, cur_right) {
+                    result_children.push(MergedCSTNode::conflict(
+                        ConflictSource::OrderedMerge,
+                        None,
+                        Some(matching_base_right.matching_node),
+                        Some(cur_right),
+                        log_state,
+                    ));
                 }
 
                 cur_right_option = children_right_it.next();
@@ -217,11 +222,14 @@ pub fn ordered_merge<'a>(
                     ls.current_unstable.right_nodes.push(cur_right);
                 }
 
-                if !matching_base_right.is_perfect_match {
-                    result_children.push(MergedCSTNode::Conflict {
-                        left: None,
-                        right: Some(Box::new(cur_right.into())),
-                    })
+                if !is_unchanged(&matching_base_right, cur_right) {
+                    result_children.push(MergedCSTNode::conflict(
+                        ConflictSource::OrderedMerge,
+                        None,
+                        Some(matching_base_right.matching_node),
+                        Some(cur_right),
+                        log_state,
+                    ))
                 }
                 cur_right_option = children_right_it.next();
             }
@@ -246,11 +254,14 @@ pub fn ordered_merge<'a>(
                     ls.current_unstable.left_nodes.push(cur_left);
                 }
 
-                if !matching_base_left.is_perfect_match {
-                    result_children.push(MergedCSTNode::Conflict {
-                        left: Some(Box::new(cur_left.into())),
-                        right: None,
-                    });
+                if !is_unchanged(&matching_base_left, cur_left) {
+                    result_children.push(MergedCSTNode::conflict(
+                        ConflictSource::OrderedMerge,
+                        Some(cur_left),
+                        Some(matching_base_left.matching_node),
+                        None,
+                        log_state,
+                    ));
                 }
 
                 cur_left_option = children_left_it.next();
@@ -264,11 +275,14 @@ pub fn ordered_merge<'a>(
                     ls.current_unstable.left_nodes.push(cur_left);
                 }
 
-                if !matching_base_left.is_perfect_match {
-                    result_children.push(MergedCSTNode::Conflict {
-                        left: Some(Box::new(cur_left.into())),
-                        right: None,
-                    })
+                if !is_unchanged(&matching_base_left, cur_left) {
+                    result_children.push(MergedCSTNode::conflict(
+                        ConflictSource::OrderedMerge,
+                        Some(cur_left),
+                        Some(matching_base_left.matching_node),
+                        None,
+                        log_state,
+                    ))
                 }
                 cur_left_option = children_left_it.next();
             }
@@ -286,27 +300,36 @@ pub fn ordered_merge<'a>(
                 }
 
                 match (
-                    matching_base_left.is_perfect_match,
-                    matching_base_right.is_perfect_match,
+                    is_unchanged(&matching_base_left, cur_left),
+                    is_unchanged(&matching_base_right, cur_right),
                 ) {
                     (true, true) => {}
                     (true, false) => {
-                        result_children.push(MergedCSTNode::Conflict {
-                            left: Some(Box::new(cur_left.into())),
-                            right: None,
-                        });
+                        result_children.push(MergedCSTNode::conflict(
+                            ConflictSource::OrderedMerge,
+                            Some(cur_left),
+                            Some(matching_base_left.matching_node),
+                            None,
+                            log_state,
+                        ));
                     }
                     (false, true) => {
-                        result_children.push(MergedCSTNode::Conflict {
-                            left: None,
-                            right: Some(Box::new(cur_right.into())),
-                        });
+                        result_children.push(MergedCSTNode::conflict(
+                            ConflictSource::OrderedMerge,
+                            None,
+                            Some(matching_base_right.matching_node),
+                            Some(cur_right),
+                            log_state,
+                        ));
                     }
                     (false, false) => {
-                        result_children.push(MergedCSTNode::Conflict {
-                            left: Some(Box::new(cur_left.into())),
-                            right: Some(Box::new(cur_right.into())),
-                        });
+                        result_children.push(MergedCSTNode::conflict(
+                            ConflictSource::OrderedMerge,
+                            Some(cur_left),
+                            Some(matching_base_left.matching_node),
+                            Some(cur_right),
+                            log_state,
+                        ));
                     }
                 }
 
@@ -322,11 +345,14 @@ pub fn ordered_merge<'a>(
                     ls.current_unstable.left_nodes.push(cur_left);
                 }
 
-                if !matching_base_left.is_perfect_match {
-                    result_children.push(MergedCSTNode::Conflict {
-                        left: Some(Box::new(cur_left.into())),
-                        right: Some(Box::new(cur_right.into())),
-                    });
+                if !is_unchanged(&matching_base_left, cur_left) {
+                    result_children.push(MergedCSTNode::conflict(
+                        ConflictSource::OrderedMerge,
+                        Some(cur_left),
+                        Some(matching_base_left.matching_node),
+                        Some(cur_right),
+                        log_state,
+                    ));
                 } else {
                     result_children.push(cur_right.into());
                 }
@@ -368,11 +394,14 @@ pub fn ordered_merge<'a>(
                     ls.current_unstable.right_nodes.push(cur_right);
                 }
 
-                if !matching_base_right.is_perfect_match {
-                    result_children.push(MergedCSTNode::Conflict {
-                        left: Some(Box::new(cur_left.into())),
-                        right: Some(Box::new(cur_right.into())),
-                    })
+                if !is_unchanged(&matching_base_right, cur_right) {
+                    result_children.push(MergedCSTNode::conflict(
+                        ConflictSource::OrderedMerge,
+                        Some(cur_left),
+                        Some(matching_base_right.matching_node),
+                        Some(cur_right),
+                        log_state,
+                    ))
                 } else {
                     result_children.push(cur_left.into());
                 }
@@ -390,10 +419,13 @@ pub fn ordered_merge<'a>(
                     ls.current_unstable.right_nodes.push(cur_right);
                 }
 
-                result_children.push(MergedCSTNode::Conflict {
-                    left: Some(Box::new(cur_left.into())),
-                    right: Some(Box::new(cur_right.into())),
-                });
+                result_children.push(MergedCSTNode::conflict(
+                    ConflictSource::OrderedMerge,
+                    Some(cur_left),
+                    base_child_at_same_position(base, left, cur_left, right, cur_right),
+                    Some(cur_right),
+                    log_state,
+                ));
 
                 cur_left_option = children_left_it.next();
                 cur_right_option = children_right_it.next();
@@ -421,10 +453,13 @@ pub fn ordered_merge<'a>(
                 if cur_left.contents() == cur_right.contents() {
                     result_children.push(cur_left.into())
                 } else {
-                    result_children.push(MergedCSTNode::Conflict {
-                        left: Some(Box::new(cur_left.into())),
-                        right: Some(Box::new(cur_right.into())),
-                    })
+                    result_children.push(MergedCSTNode::conflict(
+                        ConflictSource::OrderedMerge,
+                        Some(cur_left),
+                        c.or(e).map(|matching| matching.matching_node),
+                        Some(cur_right),
+                        log_state,
+                    ))
                 }
 
                 cur_left_option = children_left_it.next();
@@ -469,10 +504,31 @@ pub fn ordered_merge<'a>(
     Ok(MergedCSTNode::NonTerminal {
         kind: left.kind,
         children: result_children,
-        leading_white_space: left.leading_white_space,
+        leading_white_space: left.leading_white_space.map(std::borrow::Cow::Borrowed),
     })
 }
 
+/// The child of `base` at the position of both `left_child` and `right_child`, which stands for
+/// their base version when neither was matched to one
+fn base_child_at_same_position<'a>(
+    base: Option<&'a NonTerminal<'a>>,
+    left: &'a NonTerminal<'a>,
+    left_child: &'a CSTNode<'a>,
+    right: &'a NonTerminal<'a>,
+    right_child: &'a CSTNode<'a>,
+) -> Option<&'a CSTNode<'a>> {
+    let position = |parent: &'a NonTerminal<'a>, child: &'a CSTNode<'a>| {
+        parent
+            .get_children()
+            .iter()
+            .position(|sibling| std::ptr::eq(sibling, child))
+    };
+    let index =
+        position(left, left_child).filter(|&index| position(right, right_child) == Some(index))?;
+    base.and_then(|base| base.get_children().get(index))
+        .filter(|base_child| base_child.kind() == left_child.kind())
+}
+
 #[cfg(test)]
 mod tests {
     use std::{borrow::Cow, vec};
@@ -496,6 +552,7 @@ mod tests {
         let matchings_parents = matching::calculate_matchings(parent_a, parent_b);
 
         let merged_tree = ordered_merge(
+            None,
             parent_a.try_into().unwrap(),
             parent_b.try_into().unwrap(),
             &matchings_base_parent_a,
@@ -504,6 +561,7 @@ mod tests {
             &mut log_state,
         )?;
         let merged_tree_swap = ordered_merge(
+            None,
             parent_b.try_into().unwrap(),
             parent_a.try_into().unwrap(),
             &matchings_base_parent_b,
@@ -530,6 +588,7 @@ mod tests {
         let matchings_parents = matching::calculate_matchings(parent_a, parent_b);
 
         let merged_tree = ordered_merge(
+            None,
             parent_a.try_into().unwrap(),
             parent_b.try_into().unwrap(),
             &matchings_base_parent_a,
@@ -935,6 +994,7 @@ mod tests {
         let matchings_parents = matching::calculate_matchings(&parent_a, &parent_b);
 
         let merged_tree = ordered_merge(
+            None,
             (&parent_a).try_into().unwrap(),
             (&parent_b).try_into().unwrap(),
             &matchings_base_parent_a,
@@ -943,6 +1003,7 @@ mod tests {
             &mut log_state,
         )?;
         let merged_tree_swap = ordered_merge(
+            None,
             (&parent_b).try_into().unwrap(),
             (&parent_a).try_into().unwrap(),
             &matchings_base_parent_b,
@@ -965,6 +1026,15 @@ mod tests {
                             value: Cow::Borrowed("value_b"),
                         }],
                     })),
+                    base: Some(Box::new(MergedCSTNode::NonTerminal {
+                        kind: "subtree",
+                        leading_white_space: None,
+                        children: vec![MergedCSTNode::Terminal {
+                            kind: "kind_a",
+                            leading_white_space: None,
+                            value: Cow::Borrowed("value_a"),
+                        }],
+                    })),
                     right: Some(Box::new(MergedCSTNode::NonTerminal {
                         kind: "subtree",
                         leading_white_space: None,
@@ -974,6 +1044,7 @@ mod tests {
                             value: Cow::Borrowed("value_c"),
                         }],
                     })),
+                    record: None,
                 },],
             },
             merged_tree
@@ -993,6 +1064,15 @@ mod tests {
                             value: Cow::Borrowed("value_c"),
                         }],
                     })),
+                    base: Some(Box::new(MergedCSTNode::NonTerminal {
+                        kind: "subtree",
+                        leading_white_space: None,
+                        children: vec![MergedCSTNode::Terminal {
+                            kind: "kind_a",
+                            leading_white_space: None,
+                            value: Cow::Borrowed("value_a"),
+                        }],
+                    })),
                     right: Some(Box::new(MergedCSTNode::NonTerminal {
                         kind: "another_subtree",
                         leading_white_space: None,
@@ -1002,6 +1082,7 @@ mod tests {
                             value: Cow::Borrowed("value_b"),
                         }],
                     })),
+                    record: None,
                 },],
             },
             merged_tree_swap
@@ -1069,11 +1150,13 @@ mod tests {
                         leading_white_space: None,
                         value: Cow::Borrowed("value_a"),
                     })),
+                    base: None,
                     right: Some(Box::new(MergedCSTNode::Terminal {
                         kind: "kind_b",
                         leading_white_space: None,
                         value: Cow::Borrowed("value_b"),
                     })),
+                    record: None,
                 }],
             },
         )
@@ -1274,7 +1357,17 @@ mod tests {
                                 value: Cow::Borrowed("value_c"),
                             }],
                         })),
+                        base: Some(Box::new(MergedCSTNode::NonTerminal {
+                            kind: "subtree",
+                            leading_white_space: None,
+                            children: vec![MergedCSTNode::Terminal {
+                                kind: "kind_b",
+                                leading_white_space: None,
+                                value: Cow::Borrowed("value_b"),
+                            }],
+                        })),
                         right: None,
+                        record: None,
                     },
                     MergedCSTNode::Terminal {
                         kind: "kind_a",
@@ -1295,6 +1388,15 @@ mod tests {
                 children: vec![
                     MergedCSTNode::Conflict {
                         left: None,
+                        base: Some(Box::new(MergedCSTNode::NonTerminal {
+                            kind: "subtree",
+                            leading_white_space: None,
+                            children: vec![MergedCSTNode::Terminal {
+                                kind: "kind_b",
+                                leading_white_space: None,
+                                value: Cow::Borrowed("value_b"),
+                            }],
+                        })),
                         right: Some(Box::new(MergedCSTNode::NonTerminal {
                             kind: "subtree",
                             leading_white_space: None,
@@ -1304,6 +1406,7 @@ mod tests {
                                 value: Cow::Borrowed("value_c"),
                             }],
                         })),
+                        record: None,
                     },
                     MergedCSTNode::Terminal {
                         kind: "kind_a",
@@ -1607,6 +1710,15 @@ mod tests {
                 children: vec![
                     MergedCSTNode::Conflict {
                         left: None,
+                        base: Some(Box::new(MergedCSTNode::NonTerminal {
+                            kind: "subtree",
+                            leading_white_space: None,
+                            children: vec![MergedCSTNode::Terminal {
+                                kind: "kind_b",
+                                leading_white_space: None,
+                                value: Cow::Borrowed("value_b"),
+                            }],
+                        })),
                         right: Some(Box::new(MergedCSTNode::NonTerminal {
                             kind: "subtree",
                             leading_white_space: None,
@@ -1616,6 +1728,7 @@ mod tests {
                                 value: Cow::Borrowed("value_c"),
                             }],
                         })),
+                        record: None,
                     },
                     MergedCSTNode::Terminal {
                         kind: "kind_a",
@@ -1643,7 +1756,17 @@ mod tests {
                                 leading_white_space: None,
                             }],
                         })),
+                        base: Some(Box::new(MergedCSTNode::NonTerminal {
+                            kind: "subtree",
+                            leading_white_space: None,
+                            children: vec![MergedCSTNode::Terminal {
+                                kind: "kind_b",
+                                leading_white_space: None,
+                                value: Cow::Borrowed("value_b"),
+                            }],
+                        })),
                         right: None,
+                        record: None,
                     },
                     MergedCSTNode::Terminal {
                         kind: "kind_a",
@@ -1938,7 +2061,17 @@ mod tests {
                             leading_white_space: None,
                         }],
                     })),
+                    base: Some(Box::new(MergedCSTNode::NonTerminal {
+                        kind: "subtree_b",
+                        leading_white_space: None,
+                        children: vec![MergedCSTNode::Terminal {
+                            kind: "kind_c",
+                            leading_white_space: None,
+                            value: Cow::Borrowed("value_c"),
+                        }],
+                    })),
                     right: None,
+                    record: None,
                 }],
             },
         )?;
@@ -1951,6 +2084,15 @@ mod tests {
                 leading_white_space: None,
                 children: vec![MergedCSTNode::Conflict {
                     left: None,
+                    base: Some(Box::new(MergedCSTNode::NonTerminal {
+                        kind: "subtree_b",
+                        leading_white_space: None,
+                        children: vec![MergedCSTNode::Terminal {
+                            kind: "kind_c",
+                            leading_white_space: None,
+                            value: Cow::Borrowed("value_c"),
+                        }],
+                    })),
                     right: Some(Box::new(MergedCSTNode::NonTerminal {
                         kind: "subtree_b",
                         children: vec![MergedCSTNode::Terminal {
@@ -1960,6 +2102,7 @@ mod tests {
                         }],
                         leading_white_space: None,
                     })),
+                    record: None,
                 }],
             },
         )
@@ -1989,6 +2132,7 @@ mod tests {
 
         let matchings = Matchings::empty();
         let result = ordered_merge(
+            None,
             &kind_a,
             &kind_b,
             &matchings,
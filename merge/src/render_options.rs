@@ -0,0 +1,117 @@
+pub const DEFAULT_MARKER_SIZE: usize = 7;
+
+/// How conflicts should be resolved while rendering instead of emitting markers.
+/// Mirrors the `--ours`, `--theirs` and `--union` options of `git merge-file`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictFavor {
+    Ours,
+    Theirs,
+    Union,
+}
+
+/// Which sections are rendered for each conflict.
+/// `Diff3` adds a `|||||||` section with the base version between both sides.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConflictStyle {
+    #[default]
+    Merge,
+    Diff3,
+}
+
+impl std::str::FromStr for ConflictStyle {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "merge" => Ok(ConflictStyle::Merge),
+            "diff3" => Ok(ConflictStyle::Diff3),
+            _ => Err(format!("Invalid conflict style provided: {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderOptions {
+    pub left_label: Option<String>,
+    pub base_label: Option<String>,
+    pub right_label: Option<String>,
+    pub marker_size: usize,
+    pub style: ConflictStyle,
+    pub favor: Option<ConflictFavor>,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            left_label: None,
+            base_label: None,
+            right_label: None,
+            marker_size: DEFAULT_MARKER_SIZE,
+            style: ConflictStyle::Merge,
+            favor: None,
+        }
+    }
+}
+
+impl RenderOptions {
+    pub fn start_marker(&self) -> String {
+        Self::marker('<', self.marker_size, self.left_label.as_deref())
+    }
+
+    pub fn base_marker(&self) -> String {
+        Self::marker('|', self.marker_size, self.base_label.as_deref())
+    }
+
+    pub fn separator_marker(&self) -> String {
+        Self::marker('=', self.marker_size, None)
+    }
+
+    pub fn end_marker(&self) -> String {
+        Self::marker('>', self.marker_size, self.right_label.as_deref())
+    }
+
+    fn marker(character: char, size: usize, label: Option<&str>) -> String {
+        let marker = character.to_string().repeat(size);
+        match label {
+            Some(label) => format!("{marker} {label}"),
+            None => marker,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConflictStyle, RenderOptions};
+
+    #[test]
+    fn by_default_markers_have_seven_characters_and_no_labels() {
+        let options = RenderOptions::default();
+
+        assert_eq!(options.start_marker(), "<<<<<<<");
+        assert_eq!(options.separator_marker(), "=======");
+        assert_eq!(options.end_marker(), ">>>>>>>");
+    }
+
+    #[test]
+    fn markers_include_labels_and_respect_the_marker_size() {
+        let options = RenderOptions {
+            left_label: Some("ours".into()),
+            base_label: Some("base".into()),
+            right_label: Some("theirs".into()),
+            marker_size: 3,
+            ..Default::default()
+        };
+
+        assert_eq!(options.start_marker(), "<<< ours");
+        assert_eq!(options.base_marker(), "||| base");
+        assert_eq!(options.separator_marker(), "===");
+        assert_eq!(options.end_marker(), ">>> theirs");
+    }
+
+    #[test]
+    fn conflict_styles_are_parsed_from_their_names() {
+        assert_eq!("merge".parse(), Ok(ConflictStyle::Merge));
+        assert_eq!("diff3".parse(), Ok(ConflictStyle::Diff3));
+        assert!("zdiff3".parse::<ConflictStyle>().is_err());
+    }
+}
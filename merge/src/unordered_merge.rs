@@ -1,9 +1,11 @@
 use std::collections::HashSet;
 
 use matching::Matchings;
-use model::cst_node::NonTerminal;
+use model::{cst_node::NonTerminal, CSTNode};
 
-use crate::log_structures::{LogState, MergeChunk};
+use crate::indentation::realign_children;
+use crate::log_structures::{ConflictSource, LogState, MergeChunk};
+use crate::merge::is_unchanged;
 use crate::{merge, MergeError, MergedCSTNode};
 
 pub fn unordered_merge<'a>(
@@ -34,6 +36,53 @@ pub fn unordered_merge<'a>(
         let matching_base_left = base_left_matchings.find_matching_for(left_child);
         let matching_left_right = left_right_matchings.find_matching_for(left_child);
 
+        let is_kept = matching_base_left.is_none() || matching_left_right.is_some();
+        let duplicates: Vec<_> = if is_kept {
+            find_duplicate_additions(left_child, right, base_right_matchings)
+                .into_iter()
+                .filter(|duplicate| !processed_nodes.contains(&duplicate.id()))
+                .collect()
+        } else {
+            vec![]
+        };
+        // Right entries with the key are only duplicates if one of them is not the left entry
+        if duplicates.iter().any(|duplicate| {
+            matching_left_right
+                .as_ref()
+                .is_none_or(|matching| matching.matching_node.id() != duplicate.id())
+        }) {
+            if let Some(ls) = log_state.as_mut() {
+                if !ls.current_stable.is_empty() {
+                    ls.log
+                        .push(MergeChunk::Stable(std::mem::take(&mut ls.current_stable)));
+                }
+                ls.current_unstable.left_nodes.push(left_child);
+                ls.current_unstable.right_nodes.extend(&duplicates);
+            }
+
+            let conflict = MergedCSTNode::conflict(
+                ConflictSource::DuplicateIdentifier,
+                Some(left_child),
+                None,
+                duplicates.first().copied(),
+                log_state,
+            );
+            result_children.push(match conflict {
+                MergedCSTNode::Conflict {
+                    left, base, record, ..
+                } if duplicates.len() > 1 => MergedCSTNode::Conflict {
+                    left,
+                    base,
+                    right: Some(Box::new(join_entries(right, &duplicates))),
+                    record,
+                },
+                conflict => conflict,
+            });
+            processed_nodes.insert(left_child.id());
+            processed_nodes.extend(duplicates.iter().map(|duplicate| duplicate.id()));
+            continue;
+        }
+
         match (matching_base_left, matching_left_right) {
             // Added only by left
             (None, None) => {
@@ -76,7 +125,7 @@ pub fn unordered_merge<'a>(
             // Removed in right
             (Some(matching_base_left), None) => {
                 // Changed in left, conflict!
-                if !matching_base_left.is_perfect_match {
+                if !is_unchanged(&matching_base_left, left_child) {
                     if let Some(ls) = log_state.as_mut() {
                         if !ls.current_stable.is_empty() {
                             ls.log
@@ -88,10 +137,13 @@ pub fn unordered_merge<'a>(
                             .push(matching_base_left.matching_node);
                     }
 
-                    result_children.push(MergedCSTNode::Conflict {
-                        left: Some(Box::new(left_child.into())),
-                        right: None,
-                    })
+                    result_children.push(MergedCSTNode::conflict(
+                        ConflictSource::UnorderedMerge,
+                        Some(left_child),
+                        Some(matching_base_left.matching_node),
+                        None,
+                        log_state,
+                    ))
                 }
                 processed_nodes.insert(left_child.id());
             }
@@ -167,7 +219,7 @@ pub fn unordered_merge<'a>(
             // Removed in left
             (Some(matching_base_right), None) => {
                 // Changed in right, conflict!
-                if !matching_base_right.is_perfect_match {
+                if !is_unchanged(&matching_base_right, right_child) {
                     if let Some(ls) = log_state.as_mut() {
                         if !ls.current_stable.is_empty() {
                             ls.log
@@ -179,10 +231,13 @@ pub fn unordered_merge<'a>(
                             .push(matching_base_right.matching_node);
                     }
 
-                    result_children.push(MergedCSTNode::Conflict {
-                        left: None,
-                        right: Some(Box::new(right_child.into())),
-                    })
+                    result_children.push(MergedCSTNode::conflict(
+                        ConflictSource::UnorderedMerge,
+                        None,
+                        Some(matching_base_right.matching_node),
+                        Some(right_child),
+                        log_state,
+                    ))
                 }
             }
             (Some(_), Some(matching_left_right)) => {
@@ -211,6 +266,12 @@ pub fn unordered_merge<'a>(
         }
     }
 
+    let result_children = if left.are_children_aligned {
+        realign_children(left, result_children)
+    } else {
+        result_children
+    };
+
     let mut final_children = Vec::with_capacity((result_children.len() * 2) + 1);
     if let Some(start_delimiter) = left.delimiters.map(|delimiter| delimiter.start()) {
         final_children.push(MergedCSTNode::Terminal {
@@ -219,7 +280,8 @@ pub fn unordered_merge<'a>(
             leading_white_space: left
                 .get_children()
                 .first()
-                .and_then(|v| v.leading_white_space()),
+                .and_then(|v| v.leading_white_space())
+                .map(std::borrow::Cow::Borrowed),
         });
     }
 
@@ -249,17 +311,83 @@ pub fn unordered_merge<'a>(
             leading_white_space: left
                 .get_children()
                 .last()
-                .and_then(|v| v.leading_white_space()),
+                .and_then(|v| v.leading_white_space())
+                .map(std::borrow::Cow::Borrowed),
         });
     }
 
     Ok(MergedCSTNode::NonTerminal {
         kind: left.kind,
         children: final_children,
-        leading_white_space: left.leading_white_space,
+        leading_white_space: left.leading_white_space.map(std::borrow::Cow::Borrowed),
     })
 }
 
+/// Entries of separated lists, such as JSON objects, are keyed by their identifiers, so two of
+/// them may never share one. Finds the entries added by right with the same identifier as
+/// `left_child`, which would end up next to it in the merged list.
+fn find_duplicate_additions<'a>(
+    left_child: &'a CSTNode<'a>,
+    right: &'a NonTerminal<'a>,
+    base_right_matchings: &'a Matchings<'a>,
+) -> Vec<&'a CSTNode<'a>> {
+    let has_separator = right
+        .delimiters
+        .and_then(|delimiters| delimiters.separator())
+        .is_some();
+    let identifier = match left_child {
+        CSTNode::NonTerminal(left_entry) if has_separator => left_entry.get_identifier(),
+        _ => None,
+    };
+    let Some(identifier) = identifier else {
+        return vec![];
+    };
+
+    right
+        .get_children()
+        .iter()
+        .filter(|right_child| {
+            matches!(right_child, CSTNode::NonTerminal(right_entry)
+                if right_entry.kind == left_child.kind()
+                    && right_entry.get_identifier() == Some(identifier))
+                && base_right_matchings
+                    .find_matching_for(right_child)
+                    .is_none()
+        })
+        .collect()
+}
+
+/// Renders `entries` of the separated list `parent` as a single side of a conflict
+fn join_entries<'a>(parent: &'a NonTerminal<'a>, entries: &[&'a CSTNode<'a>]) -> MergedCSTNode<'a> {
+    let separator = parent
+        .delimiters
+        .and_then(|delimiters| delimiters.separator())
+        .unwrap_or_default();
+
+    let mut children = Vec::with_capacity(entries.len() * 2);
+    for (index, entry) in entries.iter().enumerate() {
+        if index == 0 {
+            // The white space before the first entry goes before the whole side
+            children.push(MergedCSTNode::from(*entry).with_leading_white_space(None));
+        } else {
+            children.push(MergedCSTNode::Terminal {
+                kind: "SYNTHETIC_MERGE_DELIMITER",
+                value: std::borrow::Cow::Borrowed(separator),
+                leading_white_space: None,
+            });
+            children.push(MergedCSTNode::from(*entry));
+        }
+    }
+
+    MergedCSTNode::NonTerminal {
+        kind: parent.kind,
+        children,
+        leading_white_space: entries[0]
+            .leading_white_space()
+            .map(std::borrow::Cow::Borrowed),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use matching::Matchings;
@@ -337,6 +465,7 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            are_children_aligned: false,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             identifier: None,
@@ -369,6 +498,7 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            are_children_aligned: false,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             identifier: None,
@@ -409,6 +539,7 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            are_children_aligned: false,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             delimiters: Some(&Delimiters::new("{", "}")),
@@ -469,6 +600,7 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            are_children_aligned: false,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             identifier: None,
@@ -501,6 +633,7 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            are_children_aligned: false,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             identifier: None,
@@ -549,6 +682,7 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            are_children_aligned: false,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             identifier: None,
@@ -802,6 +936,7 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            are_children_aligned: false,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             identifier: None,
@@ -877,6 +1012,7 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            are_children_aligned: false,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             identifier: None,
@@ -953,6 +1089,7 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            are_children_aligned: false,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             identifier: None,
@@ -1020,7 +1157,34 @@ mod tests {
                                 },
                             ],
                         })),
+                        base: Some(Box::new(MergedCSTNode::NonTerminal {
+                            kind: "method_declaration",
+                            leading_white_space: None,
+                            children: vec![
+                                MergedCSTNode::Terminal {
+                                    kind: "formal_parameters",
+                                    value: std::borrow::Cow::Borrowed("formal_parameters"),
+                                    leading_white_space: None,
+                                },
+                                MergedCSTNode::Terminal {
+                                    kind: "identifier",
+                                    value: std::borrow::Cow::Borrowed("method"),
+                                    leading_white_space: None,
+                                },
+                                MergedCSTNode::Terminal {
+                                    kind: "kind_a",
+                                    value: std::borrow::Cow::Borrowed("value_a"),
+                                    leading_white_space: None,
+                                },
+                                MergedCSTNode::Terminal {
+                                    kind: "kind_b",
+                                    value: std::borrow::Cow::Borrowed("value_b"),
+                                    leading_white_space: None,
+                                },
+                            ],
+                        })),
                         right: None,
+                        record: None,
                     },
                     MergedCSTNode::Terminal {
                         kind: "SYNTHETIC_MERGE_DELIMITER",
@@ -1047,6 +1211,32 @@ mod tests {
                     },
                     MergedCSTNode::Conflict {
                         left: None,
+                        base: Some(Box::new(MergedCSTNode::NonTerminal {
+                            kind: "method_declaration",
+                            leading_white_space: None,
+                            children: vec![
+                                MergedCSTNode::Terminal {
+                                    kind: "formal_parameters",
+                                    value: std::borrow::Cow::Borrowed("formal_parameters"),
+                                    leading_white_space: None,
+                                },
+                                MergedCSTNode::Terminal {
+                                    kind: "identifier",
+                                    value: std::borrow::Cow::Borrowed("method"),
+                                    leading_white_space: None,
+                                },
+                                MergedCSTNode::Terminal {
+                                    kind: "kind_a",
+                                    value: std::borrow::Cow::Borrowed("value_a"),
+                                    leading_white_space: None,
+                                },
+                                MergedCSTNode::Terminal {
+                                    kind: "kind_b",
+                                    value: std::borrow::Cow::Borrowed("value_b"),
+                                    leading_white_space: None,
+                                },
+                            ],
+                        })),
                         right: Some(Box::new(MergedCSTNode::NonTerminal {
                             kind: "method_declaration",
                             leading_white_space: None,
@@ -1073,6 +1263,7 @@ mod tests {
                                 },
                             ],
                         })),
+                        record: None,
                     },
                     MergedCSTNode::Terminal {
                         kind: "SYNTHETIC_MERGE_DELIMITER",
@@ -1084,6 +1275,58 @@ mod tests {
         )
     }
 
+    #[test]
+    fn it_only_realigns_the_children_of_containers_whose_children_are_aligned(
+    ) -> Result<(), MergeError> {
+        let member = |value, leading_white_space| {
+            CSTNode::Terminal(Terminal {
+                id: uuid::Uuid::new_v4(),
+                kind: "member",
+                value,
+                leading_white_space: Some(leading_white_space),
+                ..Default::default()
+            })
+        };
+        let container = |children, are_children_aligned| NonTerminal {
+            id: uuid::Uuid::new_v4(),
+            kind: "block",
+            children,
+            are_children_unordered: true,
+            are_children_aligned,
+            ..Default::default()
+        };
+
+        for (are_children_aligned, expected) in [(false, "\n  a\n  bc"), (true, "\n  a\n  b\n  c")]
+        {
+            let base =
+                CSTNode::NonTerminal(container(vec![member("a", "\n  ")], are_children_aligned));
+            let left = CSTNode::NonTerminal(container(
+                vec![member("a", "\n  "), member("b", "\n  ")],
+                are_children_aligned,
+            ));
+            let right = CSTNode::NonTerminal(container(
+                vec![member("c", ""), member("a", "\n  ")],
+                are_children_aligned,
+            ));
+
+            let matchings_base_left = matching::calculate_matchings(&base, &left);
+            let matchings_base_right = matching::calculate_matchings(&base, &right);
+            let matchings_left_right = matching::calculate_matchings(&left, &right);
+            let merged = unordered_merge(
+                (&left).try_into().unwrap(),
+                (&right).try_into().unwrap(),
+                &matchings_base_left,
+                &matchings_base_right,
+                &matchings_left_right,
+                &mut None,
+            )?;
+
+            assert_eq!(merged.to_string(), expected);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn i_get_an_error_if_i_try_to_merge_nodes_of_different_kinds() {
         let mut log_state = None;
@@ -92,7 +92,7 @@ pub struct Delimiters<'a> {
 }
 
 impl Delimiters<'_> {
-    pub fn new<'a>(start: &'a str, end: &'a str) -> Delimiters<'a> {
+    pub const fn new<'a>(start: &'a str, end: &'a str) -> Delimiters<'a> {
         Delimiters {
             start,
             end,
@@ -100,7 +100,7 @@ impl Delimiters<'_> {
         }
     }
 
-    pub fn new_with_separator<'a>(
+    pub const fn new_with_separator<'a>(
         start: &'a str,
         end: &'a str,
         separator: &'a str,
@@ -144,6 +144,9 @@ pub struct NonTerminal<'a> {
     pub start_position: Point,
     pub end_position: Point,
     pub are_children_unordered: bool,
+    /// Whether merges keep each child on a line of its own, at the indentation of its siblings,
+    /// as Python blocks need
+    pub are_children_aligned: bool,
     pub identifier: Option<Vec<&'a str>>,
     pub leading_white_space: Option<&'a str>,
     pub delimiters: Option<&'a Delimiters<'a>>,
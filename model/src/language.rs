@@ -4,4 +4,42 @@ pub enum Language {
     CSharp,
     JavaScript,
     Go,
+    TypeScript,
+    Tsx,
+    Python,
+    Rust,
+    Kotlin,
+    C,
+    Cpp,
+    Json,
+    Yaml,
+    Toml,
+    Xml,
+    Proto,
+}
+
+impl std::str::FromStr for Language {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "java" => Ok(Language::Java),
+            "csharp" | "cs" => Ok(Language::CSharp),
+            "javascript" | "js" => Ok(Language::JavaScript),
+            "go" => Ok(Language::Go),
+            "typescript" | "ts" => Ok(Language::TypeScript),
+            "tsx" => Ok(Language::Tsx),
+            "python" | "py" => Ok(Language::Python),
+            "rust" | "rs" => Ok(Language::Rust),
+            "kotlin" | "kt" => Ok(Language::Kotlin),
+            "c" => Ok(Language::C),
+            "cpp" | "c++" | "cxx" => Ok(Language::Cpp),
+            "json" => Ok(Language::Json),
+            "yaml" | "yml" => Ok(Language::Yaml),
+            "toml" => Ok(Language::Toml),
+            "xml" => Ok(Language::Xml),
+            "proto" | "protobuf" => Ok(Language::Proto),
+            _ => Err(format!("Invalid language provided: {name}")),
+        }
+    }
 }
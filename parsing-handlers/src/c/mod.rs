@@ -0,0 +1,36 @@
+use crate::{parsing_handlers::parsing_handlers, GroupSiblings, ParsingHandlers, SiblingKinds};
+
+mod tweak_field_declaration_list;
+
+/// Each run of `#include` lines is grouped, so includes never move across other lines such as a
+/// `#define` or an `#ifdef` block. Preprocessor conditionals are searched too, as header guards
+/// wrap the whole file.
+const INCLUDES: GroupSiblings = GroupSiblings {
+    name: "tweak_includes",
+    parent_kinds: &[
+        "translation_unit",
+        "declaration_list",
+        "preproc_if",
+        "preproc_ifdef",
+        "preproc_else",
+        "preproc_elif",
+        "preproc_elifdef",
+    ],
+    sibling_kinds: SiblingKinds::Only(&["preproc_include"]),
+    group_kind: "preproc_include_block",
+    delimiters: None,
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: false,
+};
+
+/// C and C++ share their handlers, as the C++ grammar extends the C one
+pub fn get_default_c_parsing_handlers() -> ParsingHandlers {
+    let mut handlers = parsing_handlers![
+        tweak_field_declaration_list::tweak_field_declaration_list
+            => tweak_field_declaration_list::KINDS,
+    ];
+    handlers.push(INCLUDES);
+    handlers
+}
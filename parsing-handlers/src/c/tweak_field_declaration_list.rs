@@ -0,0 +1,169 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+use crate::rewrite::{group_nodes, rewrite_non_terminal};
+
+pub(super) const KINDS: &[&str] = &["field_declaration_list"];
+
+/// Children that members may never be moved across: access labels, such as `public:`, and
+/// preprocessor lines, such as `#ifdef` blocks
+fn is_fence(node: &CSTNode) -> bool {
+    matches!(node.kind(), "access_specifier" | ":") || node.kind().starts_with("preproc_")
+}
+
+/// Class and struct bodies are unordered, but members may only be moved within the same access
+/// section and preprocessor conditional. When a body has any of those, it is made ordered and
+/// each run of members between them is grouped into an unordered node.
+pub fn tweak_field_declaration_list(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::NonTerminal(list)
+            if KINDS.contains(&list.kind) && list.children.iter().any(is_fence) =>
+        {
+            split_into_groups(list)
+        }
+        _ => root,
+    }
+}
+
+fn split_into_groups(list: NonTerminal<'_>) -> CSTNode<'_> {
+    rewrite_non_terminal(list, |list| {
+        let delimiters = list.delimiters;
+        let is_delimiter =
+            |node: &CSTNode| delimiters.is_some_and(|delimiters| delimiters.is_delimiter(node));
+
+        let mut grouped_children = Vec::with_capacity(list.children.len());
+        let mut members = vec![];
+        for child in std::mem::take(&mut list.children) {
+            if is_fence(&child) || is_delimiter(&child) {
+                flush(&mut members, &mut grouped_children);
+                grouped_children.push(child);
+            } else {
+                members.push(child);
+            }
+        }
+        flush(&mut members, &mut grouped_children);
+
+        list.children = grouped_children;
+        list.are_children_unordered = false;
+        // Braces are matched like any other child once the body is ordered
+        list.delimiters = None;
+    })
+}
+
+fn flush<'a>(members: &mut Vec<CSTNode<'a>>, children: &mut Vec<CSTNode<'a>>) {
+    if !members.is_empty() {
+        children.push(CSTNode::NonTerminal(group_nodes(
+            "field_declaration_group",
+            std::mem::take(members),
+            true,
+        )));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{Delimiters, NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::tweak_field_declaration_list;
+
+    static BRACES: Delimiters<'static> = Delimiters::new("{", "}");
+
+    fn terminal(kind: &'static str) -> CSTNode<'static> {
+        CSTNode::Terminal(Terminal {
+            kind,
+            value: kind,
+            ..Default::default()
+        })
+    }
+
+    fn node(kind: &'static str) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind,
+            ..Default::default()
+        })
+    }
+
+    fn kinds<'a>(node: &'a CSTNode) -> Vec<&'a str> {
+        let CSTNode::NonTerminal(non_terminal) = node else {
+            panic!("Expected a non terminal");
+        };
+        non_terminal
+            .children
+            .iter()
+            .map(|child| child.kind())
+            .collect()
+    }
+
+    #[test]
+    fn it_groups_the_members_of_each_access_section() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "field_declaration_list",
+            children: vec![
+                terminal("{"),
+                node("access_specifier"),
+                terminal(":"),
+                node("field_declaration"),
+                node("function_definition"),
+                node("preproc_ifdef"),
+                node("access_specifier"),
+                terminal(":"),
+                node("field_declaration"),
+                terminal("}"),
+            ],
+            are_children_unordered: true,
+            delimiters: Some(&BRACES),
+            ..Default::default()
+        });
+
+        let tweaked = tweak_field_declaration_list(root);
+
+        assert_eq!(
+            kinds(&tweaked),
+            vec![
+                "{",
+                "access_specifier",
+                ":",
+                "field_declaration_group",
+                "preproc_ifdef",
+                "access_specifier",
+                ":",
+                "field_declaration_group",
+                "}"
+            ]
+        );
+        let CSTNode::NonTerminal(list) = &tweaked else {
+            unreachable!()
+        };
+        assert!(!list.are_children_unordered);
+        assert!(list.delimiters.is_none());
+        assert_eq!(
+            kinds(&list.children[3]),
+            vec!["field_declaration", "function_definition"]
+        );
+    }
+
+    #[test]
+    fn it_leaves_bodies_with_no_sections_unordered() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "field_declaration_list",
+            children: vec![
+                terminal("{"),
+                node("field_declaration"),
+                node("field_declaration"),
+                terminal("}"),
+            ],
+            are_children_unordered: true,
+            delimiters: Some(&BRACES),
+            ..Default::default()
+        });
+
+        let tweaked = tweak_field_declaration_list(root);
+
+        assert_eq!(
+            kinds(&tweaked),
+            vec!["{", "field_declaration", "field_declaration", "}"]
+        );
+    }
+}
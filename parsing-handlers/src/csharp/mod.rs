@@ -0,0 +1,17 @@
+use crate::{GroupSiblings, ParsingHandlers, SiblingKinds};
+
+const USING_DIRECTIVES: GroupSiblings = GroupSiblings {
+    name: "group_using_directives",
+    parent_kinds: &["compilation_unit"],
+    sibling_kinds: SiblingKinds::Only(&["using_directive"]),
+    group_kind: "using_directives",
+    delimiters: None,
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: false,
+};
+
+pub fn get_default_csharp_parsing_handlers() -> ParsingHandlers {
+    ParsingHandlers::new(vec![Box::new(USING_DIRECTIVES)])
+}
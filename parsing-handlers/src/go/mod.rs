@@ -1,11 +1,61 @@
-use crate::ParsingHandlers;
+use model::cst_node::Delimiters;
 
-mod tweak_declarations_list;
-mod tweak_source_file;
+use crate::{GroupSiblings, ParsingHandlers, SiblingKinds};
+
+/// Declarations after the package clause and the imports may come in any order
+const SOURCE_FILE_TAIL: GroupSiblings = GroupSiblings {
+    name: "tweak_source_file",
+    parent_kinds: &["source_file"],
+    sibling_kinds: SiblingKinds::AllBut(&["package_clause", "import_declaration"]),
+    group_kind: "source_file_synthetic_tail",
+    delimiters: None,
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: false,
+};
+
+const CONST_SPECS: GroupSiblings = GroupSiblings {
+    name: "tweak_declarations_list",
+    parent_kinds: &["const_declaration"],
+    sibling_kinds: SiblingKinds::Only(&["const_spec", "comment"]),
+    group_kind: "const_spec_list",
+    delimiters: Some(&Delimiters::new("(", ")")),
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: false,
+};
+
+const TYPE_SPECS: GroupSiblings = GroupSiblings {
+    name: "tweak_declarations_list",
+    parent_kinds: &["type_declaration"],
+    sibling_kinds: SiblingKinds::Only(&["type_spec", "comment"]),
+    group_kind: "type_spec_list",
+    delimiters: Some(&Delimiters::new("(", ")")),
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: false,
+};
+
+const METHOD_ELEMS: GroupSiblings = GroupSiblings {
+    name: "tweak_declarations_list",
+    parent_kinds: &["interface_type"],
+    sibling_kinds: SiblingKinds::Only(&["method_elem", "type_elem", "comment"]),
+    group_kind: "method_elem_list",
+    delimiters: Some(&Delimiters::new("{", "}")),
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: false,
+};
 
 pub fn get_default_go_parsing_handlers() -> ParsingHandlers {
     ParsingHandlers::new(vec![
-        tweak_source_file::tweak_source_file,
-        tweak_declarations_list::tweak_declarations_list,
+        Box::new(SOURCE_FILE_TAIL),
+        Box::new(CONST_SPECS),
+        Box::new(TYPE_SPECS),
+        Box::new(METHOD_ELEMS),
     ])
 }
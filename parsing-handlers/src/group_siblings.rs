@@ -0,0 +1,347 @@
+use std::ops::Range;
+
+use model::{
+    cst_node::{Delimiters, NonTerminal},
+    CSTNode,
+};
+
+use crate::{
+    rewrite::{group_nodes, rewrite_non_terminal},
+    ParsingHandler,
+};
+
+/// The kinds of the siblings a [`GroupSiblings`] handler groups
+#[derive(Debug, Clone, Copy)]
+pub enum SiblingKinds {
+    Only(&'static [&'static str]),
+    AllBut(&'static [&'static str]),
+}
+
+impl SiblingKinds {
+    fn contains(&self, kind: &str) -> bool {
+        match self {
+            SiblingKinds::Only(kinds) => kinds.contains(&kind),
+            SiblingKinds::AllBut(kinds) => !kinds.contains(&kind),
+        }
+    }
+}
+
+/// Wraps each run of consecutive siblings of the given kinds in a node of its own, such as the
+/// imports of a file, so they can be merged apart from the rest of their parent and, usually,
+/// regardless of their order.
+#[derive(Debug, Clone)]
+pub struct GroupSiblings {
+    /// The name the handler is enabled by in the configuration of a language
+    pub name: &'static str,
+    /// Kinds of the nodes whose children are grouped
+    pub parent_kinds: &'static [&'static str],
+    pub sibling_kinds: SiblingKinds,
+    /// Kind of the node made for each run
+    pub group_kind: &'static str,
+    /// Delimiters around a run, which become part of its group. Runs not enclosed by them are
+    /// left alone.
+    pub delimiters: Option<&'static Delimiters<'static>>,
+    pub are_children_unordered: bool,
+    /// Whether merges keep each sibling of a group on a line of its own, see
+    /// [`NonTerminal::are_children_aligned`]
+    pub are_children_aligned: bool,
+    /// White space rendered before the group, ahead of the one its first node keeps
+    pub leading_white_space: Option<&'static str>,
+    /// Groups only the first run, for siblings such as imports that are only free to move before
+    /// any other code
+    pub first_run_only: bool,
+}
+
+impl GroupSiblings {
+    fn runs(&self, children: &[CSTNode]) -> Vec<Range<usize>> {
+        let is_grouped = |node: &CSTNode| {
+            self.sibling_kinds.contains(node.kind())
+                && !self
+                    .delimiters
+                    .is_some_and(|delimiters| delimiters.is_delimiter(node))
+        };
+
+        let mut runs = vec![];
+        let mut index = 0;
+        while index < children.len() {
+            let start = index;
+            while index < children.len() && is_grouped(&children[index]) {
+                index += 1;
+            }
+            if start < index {
+                runs.push(start..index);
+                if self.first_run_only {
+                    break;
+                }
+            } else {
+                index += 1;
+            }
+        }
+
+        match self.delimiters {
+            None => runs,
+            Some(delimiters) => runs
+                .into_iter()
+                .filter(|run| {
+                    run.start > 0
+                        && children[run.start - 1].kind() == delimiters.start()
+                        && children
+                            .get(run.end)
+                            .is_some_and(|node| node.kind() == delimiters.end())
+                })
+                .map(|run| run.start - 1..run.end + 1)
+                .collect(),
+        }
+    }
+}
+
+impl ParsingHandler for GroupSiblings {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn kinds(&self) -> Option<&[&str]> {
+        Some(self.parent_kinds)
+    }
+
+    fn handle<'a>(&self, node: CSTNode<'a>) -> CSTNode<'a> {
+        let CSTNode::NonTerminal(parent) = node else {
+            return node;
+        };
+        let runs = self.runs(&parent.children);
+        if runs.is_empty() {
+            return CSTNode::NonTerminal(parent);
+        }
+
+        log::debug!(
+            "Grouping {} runs of {:?} children into {}",
+            runs.len(),
+            parent.kind,
+            self.group_kind
+        );
+
+        rewrite_non_terminal(parent, |parent| {
+            for run in runs.into_iter().rev() {
+                let nodes = parent.children.drain(run.clone()).collect();
+                let group = NonTerminal {
+                    delimiters: self.delimiters,
+                    are_children_aligned: self.are_children_aligned,
+                    leading_white_space: self.leading_white_space,
+                    ..group_nodes(self.group_kind, nodes, self.are_children_unordered)
+                };
+                parent
+                    .children
+                    .insert(run.start, CSTNode::NonTerminal(group));
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{Delimiters, NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::{GroupSiblings, SiblingKinds};
+    use crate::ParsingHandler;
+
+    const CONST_SPECS: GroupSiblings = GroupSiblings {
+        name: "group_const_specs",
+        parent_kinds: &["const_declaration"],
+        sibling_kinds: SiblingKinds::Only(&["const_spec", "comment"]),
+        group_kind: "const_spec_list",
+        delimiters: Some(&Delimiters::new("(", ")")),
+        are_children_unordered: true,
+        are_children_aligned: false,
+        leading_white_space: None,
+        first_run_only: false,
+    };
+
+    fn node(kind: &'static str) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind,
+            ..Default::default()
+        })
+    }
+
+    fn terminal(kind: &'static str) -> CSTNode<'static> {
+        CSTNode::Terminal(Terminal {
+            kind,
+            value: kind,
+            ..Default::default()
+        })
+    }
+
+    fn kinds<'a>(node: &'a CSTNode<'a>) -> Vec<&'a str> {
+        match node {
+            CSTNode::NonTerminal(non_terminal) => {
+                non_terminal.children.iter().map(CSTNode::kind).collect()
+            }
+            CSTNode::Terminal(_) => panic!("Expected a non terminal"),
+        }
+    }
+
+    #[test]
+    fn each_run_of_siblings_is_grouped() {
+        let handler = GroupSiblings {
+            name: "group_imports",
+            parent_kinds: &["program"],
+            sibling_kinds: SiblingKinds::Only(&["import_statement"]),
+            group_kind: "import_statements",
+            delimiters: None,
+            are_children_unordered: true,
+            are_children_aligned: false,
+            leading_white_space: None,
+            first_run_only: false,
+        };
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "program",
+            children: vec![
+                node("import_statement"),
+                node("import_statement"),
+                node("comment"),
+                node("import_statement"),
+                node("function_declaration"),
+            ],
+            ..Default::default()
+        });
+
+        let grouped = handler.handle(root);
+
+        assert_eq!(
+            kinds(&grouped),
+            [
+                "import_statements",
+                "comment",
+                "import_statements",
+                "function_declaration"
+            ]
+        );
+        let CSTNode::NonTerminal(program) = &grouped else {
+            unreachable!()
+        };
+        let CSTNode::NonTerminal(imports) = &program.children[0] else {
+            panic!("Expected the imports to be grouped");
+        };
+        assert!(imports.are_children_unordered);
+        assert_eq!(imports.children.len(), 2);
+    }
+
+    #[test]
+    fn only_the_first_run_is_grouped_if_asked_to() {
+        let handler = GroupSiblings {
+            name: "group_imports",
+            parent_kinds: &["module"],
+            sibling_kinds: SiblingKinds::Only(&["import_statement", "import_from_statement"]),
+            group_kind: "import_statements",
+            delimiters: None,
+            are_children_unordered: true,
+            are_children_aligned: false,
+            leading_white_space: None,
+            first_run_only: true,
+        };
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "module",
+            children: vec![
+                node("expression_statement"),
+                node("import_statement"),
+                node("import_from_statement"),
+                node("if_statement"),
+                node("import_statement"),
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            kinds(&handler.handle(root)),
+            [
+                "expression_statement",
+                "import_statements",
+                "if_statement",
+                "import_statement"
+            ]
+        );
+    }
+
+    #[test]
+    fn siblings_can_be_grouped_by_the_kinds_they_do_not_have() {
+        let handler = GroupSiblings {
+            name: "group_declarations",
+            parent_kinds: &["source_file"],
+            sibling_kinds: SiblingKinds::AllBut(&["package_clause", "import_declaration"]),
+            group_kind: "declarations",
+            delimiters: None,
+            are_children_unordered: true,
+            are_children_aligned: false,
+            leading_white_space: None,
+            first_run_only: false,
+        };
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "source_file",
+            children: vec![
+                node("package_clause"),
+                node("import_declaration"),
+                node("function_declaration"),
+                node("type_declaration"),
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            kinds(&handler.handle(root)),
+            ["package_clause", "import_declaration", "declarations"]
+        );
+    }
+
+    #[test]
+    fn the_delimiters_around_a_run_are_grouped_with_it() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "const_declaration",
+            children: vec![
+                terminal("const"),
+                terminal("("),
+                node("const_spec"),
+                node("comment"),
+                node("const_spec"),
+                terminal(")"),
+            ],
+            ..Default::default()
+        });
+
+        let grouped = CONST_SPECS.handle(root);
+
+        assert_eq!(kinds(&grouped), ["const", "const_spec_list"]);
+        let CSTNode::NonTerminal(declaration) = &grouped else {
+            unreachable!()
+        };
+        let list = &declaration.children[1];
+        assert_eq!(
+            kinds(list),
+            ["(", "const_spec", "comment", "const_spec", ")"]
+        );
+        let CSTNode::NonTerminal(list) = list else {
+            unreachable!()
+        };
+        assert_eq!(list.delimiters.map(Delimiters::start), Some("("));
+    }
+
+    #[test]
+    fn runs_without_their_delimiters_are_left_alone() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "const_declaration",
+            children: vec![terminal("const"), node("const_spec")],
+            ..Default::default()
+        });
+
+        assert_eq!(CONST_SPECS.handle(root.clone()), root);
+    }
+
+    #[test]
+    fn terminals_of_the_parent_kind_are_returned_as_they_are() {
+        let root = terminal("const_declaration");
+
+        assert_eq!(CONST_SPECS.handle(root.clone()), root);
+    }
+}
@@ -1,11 +1,18 @@
-mod remove_block_comments;
-mod tweak_import_declarations;
+use crate::{GroupSiblings, ParsingHandlers, SiblingKinds};
 
-use crate::ParsingHandlers;
+const IMPORT_DECLARATIONS: GroupSiblings = GroupSiblings {
+    name: "tweak_import_declarations",
+    parent_kinds: &["program"],
+    sibling_kinds: SiblingKinds::Only(&["import_declaration"]),
+    group_kind: "import_declarations",
+    delimiters: None,
+    are_children_unordered: true,
+    are_children_aligned: false,
+    // The former Java handler put a line break before the group, kept so merges stay the same
+    leading_white_space: Some("\n"),
+    first_run_only: false,
+};
 
 pub fn get_default_java_parsing_handlers() -> ParsingHandlers {
-    ParsingHandlers::new(vec![
-        tweak_import_declarations::tweak_import_declarations,
-        remove_block_comments::remove_block_comments,
-    ])
+    ParsingHandlers::new(vec![Box::new(IMPORT_DECLARATIONS)])
 }
@@ -0,0 +1,18 @@
+use crate::{GroupSiblings, ParsingHandlers, SiblingKinds};
+
+const IMPORT_STATEMENTS: GroupSiblings = GroupSiblings {
+    name: "group_import_statements",
+    parent_kinds: &["program"],
+    sibling_kinds: SiblingKinds::Only(&["import_statement"]),
+    group_kind: "import_statements",
+    delimiters: None,
+    are_children_unordered: true,
+    // An import taken from the top of the other file brings no line break along
+    are_children_aligned: true,
+    leading_white_space: None,
+    first_run_only: false,
+};
+
+pub fn get_default_javascript_parsing_handlers() -> ParsingHandlers {
+    ParsingHandlers::new(vec![Box::new(IMPORT_STATEMENTS)])
+}
@@ -0,0 +1,18 @@
+use crate::{GroupSiblings, ParsingHandlers, SiblingKinds};
+
+/// The Kotlin grammar places each import directly in the source file
+const IMPORT_LIST: GroupSiblings = GroupSiblings {
+    name: "tweak_import_list",
+    parent_kinds: &["source_file"],
+    sibling_kinds: SiblingKinds::Only(&["import"]),
+    group_kind: "import_list",
+    delimiters: None,
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: true,
+};
+
+pub fn get_default_kotlin_parsing_handlers() -> ParsingHandlers {
+    ParsingHandlers::new(vec![Box::new(IMPORT_LIST)])
+}
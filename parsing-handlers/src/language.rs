@@ -1,5 +1,12 @@
 use crate::{
-    go::get_default_go_parsing_handlers, java::get_default_java_parsing_handlers, ParsingHandlers,
+    c::get_default_c_parsing_handlers, csharp::get_default_csharp_parsing_handlers,
+    go::get_default_go_parsing_handlers, java::get_default_java_parsing_handlers,
+    javascript::get_default_javascript_parsing_handlers,
+    kotlin::get_default_kotlin_parsing_handlers, proto::get_default_proto_parsing_handlers,
+    python::get_default_python_parsing_handlers, rust::get_default_rust_parsing_handlers,
+    toml::get_default_toml_parsing_handlers, typescript::get_default_typescript_parsing_handlers,
+    xml::get_default_xml_parsing_handlers, yaml::get_default_yaml_parsing_handlers,
+    ParsingHandlers,
 };
 use model::Language;
 
@@ -7,9 +14,19 @@ impl From<Language> for ParsingHandlers {
     fn from(language: Language) -> Self {
         match language {
             Language::Java => get_default_java_parsing_handlers(),
-            Language::CSharp => ParsingHandlers::empty(),
-            Language::JavaScript => ParsingHandlers::empty(),
+            Language::CSharp => get_default_csharp_parsing_handlers(),
+            Language::JavaScript => get_default_javascript_parsing_handlers(),
             Language::Go => get_default_go_parsing_handlers(),
+            Language::TypeScript | Language::Tsx => get_default_typescript_parsing_handlers(),
+            Language::Python => get_default_python_parsing_handlers(),
+            Language::Rust => get_default_rust_parsing_handlers(),
+            Language::Kotlin => get_default_kotlin_parsing_handlers(),
+            Language::C | Language::Cpp => get_default_c_parsing_handlers(),
+            Language::Json => ParsingHandlers::empty(),
+            Language::Yaml => get_default_yaml_parsing_handlers(),
+            Language::Toml => get_default_toml_parsing_handlers(),
+            Language::Xml => get_default_xml_parsing_handlers(),
+            Language::Proto => get_default_proto_parsing_handlers(),
         }
     }
 }
@@ -1,6 +1,22 @@
+mod c;
+mod csharp;
 mod go;
+mod group_siblings;
 mod java;
+mod javascript;
+mod kotlin;
 mod language;
 mod parsing_handlers;
+mod proto;
+mod python;
+mod rewrite;
+mod rust;
+mod toml;
+mod typescript;
+mod xml;
+mod yaml;
 
-pub use parsing_handlers::{ParsingHandler, ParsingHandlers};
+pub use group_siblings::{GroupSiblings, SiblingKinds};
+pub use parsing_handlers::{FnParsingHandler, ParsingHandler, ParsingHandlers};
+pub use rewrite::{group_nodes, rewrite_non_terminal};
+pub use xml::{UnorderedElements, MAVEN_COLLECTIONS};
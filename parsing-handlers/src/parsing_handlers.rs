@@ -1,13 +1,63 @@
 use model::CSTNode;
 
-pub type ParsingHandler = fn(root: CSTNode) -> CSTNode;
+/// Rewrites the nodes of parsed trees, which are handed to it bottom-up, children first.
+///
+/// Handlers are shared by every parse made with a configuration, so settings are kept in the
+/// implementing type and any state they gather goes behind interior mutability.
+pub trait ParsingHandler {
+    /// The name the handler is enabled by in the configuration of a language
+    fn name(&self) -> &str;
+
+    /// Kinds of the nodes handed to the handler, or every node if `None`
+    fn kinds(&self) -> Option<&[&str]> {
+        None
+    }
+
+    fn handle<'a>(&self, node: CSTNode<'a>) -> CSTNode<'a>;
+}
+
+/// A handler made of a function, which looks at the nodes of the given kinds, or every node if
+/// none are given
+pub struct FnParsingHandler {
+    name: &'static str,
+    kinds: Option<&'static [&'static str]>,
+    handler: for<'a> fn(CSTNode<'a>) -> CSTNode<'a>,
+}
+
+impl FnParsingHandler {
+    pub fn new(
+        name: &'static str,
+        kinds: Option<&'static [&'static str]>,
+        handler: for<'a> fn(CSTNode<'a>) -> CSTNode<'a>,
+    ) -> Self {
+        Self {
+            name,
+            kinds,
+            handler,
+        }
+    }
+}
+
+impl ParsingHandler for FnParsingHandler {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn kinds(&self) -> Option<&[&str]> {
+        self.kinds
+    }
+
+    fn handle<'a>(&self, node: CSTNode<'a>) -> CSTNode<'a> {
+        (self.handler)(node)
+    }
+}
 
 pub struct ParsingHandlers {
-    handlers: Vec<ParsingHandler>,
+    handlers: Vec<Box<dyn ParsingHandler>>,
 }
 
 impl ParsingHandlers {
-    pub fn new(handlers: Vec<ParsingHandler>) -> Self {
+    pub fn new(handlers: Vec<Box<dyn ParsingHandler>>) -> Self {
         Self { handlers }
     }
 
@@ -15,7 +65,133 @@ impl ParsingHandlers {
         Self::new(vec![])
     }
 
-    pub fn run<'a>(&'a self, root: CSTNode<'a>) -> CSTNode<'a> {
-        self.handlers.iter().fold(root, |acc, handler| handler(acc))
+    /// Adds `handler` after the existing ones
+    pub fn push(&mut self, handler: impl ParsingHandler + 'static) {
+        self.handlers.push(Box::new(handler));
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.handlers.iter().map(|handler| handler.name())
+    }
+
+    /// Keeps only the handlers whose name satisfies `keep`
+    pub fn retain(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.handlers.retain(|handler| keep(handler.name()));
+    }
+
+    pub fn run<'a>(&self, root: CSTNode<'a>) -> CSTNode<'a> {
+        self.handlers
+            .iter()
+            .fold(root, |node, handler| match handler.kinds() {
+                Some(kinds) if !kinds.contains(&node.kind()) => node,
+                _ => handler.handle(node),
+            })
+    }
+}
+
+/// Builds [`ParsingHandlers`] from handler functions, each named after its function and given
+/// only the nodes of the kinds after its `=>`, if any
+macro_rules! parsing_handlers {
+    ($($module:ident :: $handler:ident $(=> $kinds:expr)?),* $(,)?) => {
+        $crate::ParsingHandlers::new(vec![
+            $(Box::new($crate::FnParsingHandler::new(
+                stringify!($handler),
+                $crate::parsing_handlers::parsing_handlers!(@kinds $($kinds)?),
+                $module::$handler,
+            ))),*
+        ])
+    };
+    (@kinds) => {
+        None
+    };
+    (@kinds $kinds:expr) => {
+        Some($kinds)
+    };
+}
+
+pub(crate) use parsing_handlers;
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use model::{cst_node::Terminal, CSTNode};
+
+    use super::*;
+
+    struct CountingHandler {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl ParsingHandler for CountingHandler {
+        fn name(&self) -> &str {
+            "count_identifiers"
+        }
+
+        fn kinds(&self) -> Option<&[&str]> {
+            Some(&["identifier"])
+        }
+
+        fn handle<'a>(&self, node: CSTNode<'a>) -> CSTNode<'a> {
+            self.calls.set(self.calls.get() + 1);
+            node
+        }
+    }
+
+    #[test]
+    fn handlers_are_only_given_nodes_of_their_kinds() {
+        let calls = Rc::new(Cell::new(0));
+        let mut handlers = ParsingHandlers::empty();
+        handlers.push(CountingHandler {
+            calls: calls.clone(),
+        });
+
+        for kind in ["identifier", "comment", "identifier"] {
+            handlers.run(CSTNode::Terminal(Terminal {
+                kind,
+                ..Default::default()
+            }));
+        }
+
+        assert_eq!(calls.get(), 2);
+        assert_eq!(handlers.names().collect::<Vec<_>>(), ["count_identifiers"]);
+    }
+
+    fn value(node: CSTNode<'static>) -> &'static str {
+        match node {
+            CSTNode::Terminal(terminal) => terminal.value,
+            CSTNode::NonTerminal(_) => unreachable!(),
+        }
+    }
+
+    fn mark(node: CSTNode<'_>) -> CSTNode<'_> {
+        match node {
+            CSTNode::Terminal(terminal) => CSTNode::Terminal(Terminal {
+                value: "marked",
+                ..terminal
+            }),
+            node => node,
+        }
+    }
+
+    #[test]
+    fn function_handlers_are_only_given_nodes_of_their_kinds_if_any_are_given() {
+        let terminal = |kind| {
+            CSTNode::Terminal(Terminal {
+                kind,
+                ..Default::default()
+            })
+        };
+        let for_identifiers = ParsingHandlers::new(vec![Box::new(FnParsingHandler::new(
+            "mark",
+            Some(&["identifier"]),
+            mark,
+        ))]);
+        let for_every_node =
+            ParsingHandlers::new(vec![Box::new(FnParsingHandler::new("mark", None, mark))]);
+
+        assert_eq!(value(for_identifiers.run(terminal("identifier"))), "marked");
+        assert_eq!(value(for_identifiers.run(terminal("comment"))), "");
+        assert_eq!(value(for_every_node.run(terminal("comment"))), "marked");
     }
 }
@@ -0,0 +1,37 @@
+use crate::{parsing_handlers::parsing_handlers, GroupSiblings, ParsingHandlers, SiblingKinds};
+
+mod tweak_service_body;
+
+/// Imports and file options are placed directly in the source file, along with the messages and
+/// services. Grouping them lets the ones added by each side be merged by path and name.
+const IMPORTS: GroupSiblings = GroupSiblings {
+    name: "tweak_top_level_statements",
+    parent_kinds: &["source_file"],
+    sibling_kinds: SiblingKinds::Only(&["import"]),
+    group_kind: "import_list",
+    delimiters: None,
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: false,
+};
+
+const OPTIONS: GroupSiblings = GroupSiblings {
+    name: "tweak_top_level_statements",
+    parent_kinds: &["source_file"],
+    sibling_kinds: SiblingKinds::Only(&["option"]),
+    group_kind: "option_list",
+    delimiters: None,
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: false,
+};
+
+pub fn get_default_proto_parsing_handlers() -> ParsingHandlers {
+    let mut handlers =
+        parsing_handlers![tweak_service_body::tweak_service_body => tweak_service_body::KINDS];
+    handlers.push(IMPORTS);
+    handlers.push(OPTIONS);
+    handlers
+}
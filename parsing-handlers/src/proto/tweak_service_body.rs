@@ -0,0 +1,112 @@
+use model::CSTNode;
+
+use crate::rewrite::{group_nodes, rewrite_non_terminal};
+
+pub(super) const KINDS: &[&str] = &["service"];
+
+/// Unlike messages and enums, services have no node for their body: the rpcs and options are
+/// placed between the braces of the service itself. This groups them into an unordered node, so
+/// they are merged by name like the fields of a message.
+pub fn tweak_service_body(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::NonTerminal(service) if KINDS.contains(&service.kind) => {
+            let open_brace = service.children.iter().position(|node| node.kind() == "{");
+            let close_brace = service.children.iter().rposition(|node| node.kind() == "}");
+            let (Some(open_brace), Some(close_brace)) = (open_brace, close_brace) else {
+                return CSTNode::NonTerminal(service);
+            };
+            if close_brace <= open_brace + 1 {
+                return CSTNode::NonTerminal(service);
+            }
+
+            rewrite_non_terminal(service, |service| {
+                let members = service
+                    .children
+                    .drain(open_brace + 1..close_brace)
+                    .collect();
+                let service_body = group_nodes("service_body", members, true);
+                service
+                    .children
+                    .insert(open_brace + 1, CSTNode::NonTerminal(service_body));
+            })
+        }
+        _ => root,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::tweak_service_body;
+
+    fn node(kind: &'static str) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind,
+            ..Default::default()
+        })
+    }
+
+    fn terminal(kind: &'static str) -> CSTNode<'static> {
+        CSTNode::Terminal(Terminal {
+            kind,
+            value: kind,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn it_groups_the_members_between_the_braces() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "service",
+            children: vec![
+                terminal("service"),
+                node("service_name"),
+                terminal("{"),
+                node("option"),
+                node("rpc"),
+                node("rpc"),
+                terminal("}"),
+            ],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(service) = tweak_service_body(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        let kinds: Vec<&str> = service.children.iter().map(|child| child.kind()).collect();
+        assert_eq!(
+            kinds,
+            vec!["service", "service_name", "{", "service_body", "}"]
+        );
+        let CSTNode::NonTerminal(service_body) = &service.children[3] else {
+            panic!("Expected the members to be grouped");
+        };
+        assert!(service_body.are_children_unordered);
+        assert_eq!(service_body.children.len(), 3);
+    }
+
+    #[test]
+    fn it_leaves_empty_services_alone() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "service",
+            children: vec![
+                terminal("service"),
+                node("service_name"),
+                terminal("{"),
+                terminal("}"),
+            ],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(service) = tweak_service_body(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        assert_eq!(service.children.len(), 4);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{parsing_handlers::parsing_handlers, GroupSiblings, ParsingHandlers, SiblingKinds};
+
+mod tweak_class_body;
+
+/// Only the imports at the top of a module are grouped, as code in between imports may depend
+/// on their order
+const IMPORT_STATEMENTS: GroupSiblings = GroupSiblings {
+    name: "tweak_import_statements",
+    parent_kinds: &["module"],
+    sibling_kinds: SiblingKinds::Only(&[
+        "import_statement",
+        "import_from_statement",
+        "future_import_statement",
+    ]),
+    group_kind: "import_statements",
+    delimiters: None,
+    are_children_unordered: true,
+    are_children_aligned: true,
+    leading_white_space: None,
+    first_run_only: true,
+};
+
+pub fn get_default_python_parsing_handlers() -> ParsingHandlers {
+    let mut handlers =
+        parsing_handlers![tweak_class_body::tweak_class_body => tweak_class_body::KINDS];
+    handlers.push(IMPORT_STATEMENTS);
+    handlers
+}
@@ -0,0 +1,58 @@
+use model::CSTNode;
+
+use crate::rewrite::rewrite_non_terminal;
+
+pub(super) const KINDS: &[&str] = &["class_definition"];
+
+/// Python uses the same `block` kind for the bodies of classes, functions and statements, but
+/// only the members of a class may be freely reordered. This tells class bodies apart.
+pub fn tweak_class_body(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::NonTerminal(class) if KINDS.contains(&class.kind) => {
+            rewrite_non_terminal(class, |class| {
+                for child in class.children.iter_mut() {
+                    if let CSTNode::NonTerminal(body) = child {
+                        if body.kind == "block" {
+                            *child = rewrite_non_terminal(std::mem::take(body), |body| {
+                                body.kind = "class_body";
+                                body.are_children_unordered = true;
+                                body.are_children_aligned = true;
+                            });
+                        }
+                    }
+                }
+            })
+        }
+        _ => root,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{cst_node::NonTerminal, CSTNode};
+
+    use super::tweak_class_body;
+
+    #[test]
+    fn it_makes_the_body_of_a_class_unordered_and_aligned() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "class_definition",
+            children: vec![CSTNode::NonTerminal(NonTerminal {
+                kind: "block",
+                ..Default::default()
+            })],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(class) = tweak_class_body(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        let CSTNode::NonTerminal(body) = &class.children[0] else {
+            panic!("Expected the body to be a non terminal");
+        };
+        assert_eq!(body.kind, "class_body");
+        assert!(body.are_children_unordered);
+        assert!(body.are_children_aligned);
+    }
+}
@@ -0,0 +1,48 @@
+use std::cell::OnceCell;
+
+use model::{cst_node::NonTerminal, CSTNode};
+
+/// Applies `rewrite` to `non_terminal`, dropping the subtree sizes cached before it changed
+pub fn rewrite_non_terminal<'a>(
+    mut non_terminal: NonTerminal<'a>,
+    rewrite: impl FnOnce(&mut NonTerminal<'a>),
+) -> CSTNode<'a> {
+    rewrite(&mut non_terminal);
+    non_terminal.subtree_size = OnceCell::new();
+    non_terminal.subtree_size_without_delimiters = OnceCell::new();
+    CSTNode::NonTerminal(non_terminal)
+}
+
+/// Appends `child` to the children of `non_terminal`, which then ends where `child` does
+pub(crate) fn append_child<'a>(non_terminal: NonTerminal<'a>, child: CSTNode<'a>) -> CSTNode<'a> {
+    rewrite_non_terminal(non_terminal, |non_terminal| {
+        non_terminal.end_position = child.end_position();
+        non_terminal.children.push(child);
+    })
+}
+
+/// Wraps `nodes` in a new node of `kind`, spanning from the first to the last of them. The white
+/// space before the group stays with its first node.
+///
+/// Panics if `nodes` is empty.
+pub fn group_nodes<'a>(
+    kind: &'a str,
+    nodes: Vec<CSTNode<'a>>,
+    are_children_unordered: bool,
+) -> NonTerminal<'a> {
+    let first = nodes.first().expect("A group needs at least one node");
+    NonTerminal {
+        id: uuid::Uuid::new_v4(),
+        kind,
+        start_position: first.start_position(),
+        end_position: nodes.last().unwrap().end_position(),
+        children: nodes,
+        are_children_unordered,
+        are_children_aligned: false,
+        identifier: None,
+        leading_white_space: None,
+        delimiters: None,
+        subtree_size_without_delimiters: OnceCell::new(),
+        subtree_size: OnceCell::new(),
+    }
+}
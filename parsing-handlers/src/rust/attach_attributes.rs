@@ -0,0 +1,148 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+use crate::rewrite::rewrite_non_terminal;
+
+pub(super) const KINDS: &[&str] = &[
+    "source_file",
+    "declaration_list",
+    "field_declaration_list",
+    "enum_variant_list",
+];
+
+/// Attributes such as `#[derive]` or `#[cfg]` are siblings of the item they annotate. Moving
+/// them into the item keeps both together when unordered bodies are merged.
+pub fn attach_attributes(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::NonTerminal(container) if KINDS.contains(&container.kind) => attach(container),
+        _ => root,
+    }
+}
+
+fn attach(container: NonTerminal<'_>) -> CSTNode<'_> {
+    rewrite_non_terminal(container, |container| {
+        let mut attached_children = Vec::with_capacity(container.children.len());
+        let mut attributes = vec![];
+        for child in std::mem::take(&mut container.children) {
+            match child {
+                CSTNode::NonTerminal(attribute) if attribute.kind == "attribute_item" => {
+                    attributes.push(CSTNode::NonTerminal(attribute))
+                }
+                CSTNode::NonTerminal(item) if !attributes.is_empty() => {
+                    attached_children.push(with_attributes(item, std::mem::take(&mut attributes)))
+                }
+                child => {
+                    attached_children.append(&mut attributes);
+                    attached_children.push(child);
+                }
+            }
+        }
+        attached_children.append(&mut attributes);
+        container.children = attached_children;
+    })
+}
+
+/// The item takes the white space before its first attribute, while the white space it had is
+/// moved to its first child, so the rendering stays the same
+fn with_attributes<'a>(item: NonTerminal<'a>, mut attributes: Vec<CSTNode<'a>>) -> CSTNode<'a> {
+    rewrite_non_terminal(item, |item| {
+        if let Some(first_child) = item.children.first_mut() {
+            set_leading_white_space(first_child, item.leading_white_space);
+        }
+        item.leading_white_space = set_leading_white_space(&mut attributes[0], None);
+
+        item.start_position = attributes[0].start_position();
+        attributes.append(&mut item.children);
+        item.children = attributes;
+    })
+}
+
+/// Replaces the leading white space of `node`, returning the one it had
+fn set_leading_white_space<'a>(
+    node: &mut CSTNode<'a>,
+    leading_white_space: Option<&'a str>,
+) -> Option<&'a str> {
+    match node {
+        CSTNode::Terminal(terminal) => {
+            std::mem::replace(&mut terminal.leading_white_space, leading_white_space)
+        }
+        CSTNode::NonTerminal(non_terminal) => {
+            std::mem::replace(&mut non_terminal.leading_white_space, leading_white_space)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode, Point,
+    };
+
+    use super::attach_attributes;
+
+    fn node(kind: &'static str, row: usize, leading_white_space: &'static str) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind,
+            start_position: Point { row, column: 4 },
+            end_position: Point { row, column: 10 },
+            children: vec![CSTNode::Terminal(Terminal {
+                kind,
+                value: kind,
+                ..Default::default()
+            })],
+            leading_white_space: Some(leading_white_space),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn it_moves_attributes_into_the_item_they_annotate() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "declaration_list",
+            children: vec![
+                node("attribute_item", 1, "\n    "),
+                node("attribute_item", 2, "\n    "),
+                node("function_item", 3, "\n    "),
+            ],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(declaration_list) = attach_attributes(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        assert_eq!(declaration_list.children.len(), 1);
+        let CSTNode::NonTerminal(function) = &declaration_list.children[0] else {
+            panic!("Expected the item to be a non terminal");
+        };
+        assert_eq!(function.kind, "function_item");
+        assert_eq!(function.children.len(), 3);
+        assert_eq!(function.start_position, Point { row: 1, column: 4 });
+        assert_eq!(function.leading_white_space, Some("\n    "));
+        assert_eq!(function.children[0].leading_white_space(), None);
+        assert_eq!(function.children[2].leading_white_space(), Some("\n    "));
+    }
+
+    #[test]
+    fn it_keeps_attributes_with_no_item_after_them() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "source_file",
+            children: vec![
+                node("function_item", 0, ""),
+                node("attribute_item", 1, "\n"),
+            ],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(source_file) = attach_attributes(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        let kinds: Vec<&str> = source_file
+            .children
+            .iter()
+            .map(|child| child.kind())
+            .collect();
+        assert_eq!(kinds, vec!["function_item", "attribute_item"]);
+    }
+}
@@ -0,0 +1,135 @@
+use model::{
+    cst_node::{Delimiters, NonTerminal},
+    CSTNode,
+};
+
+use crate::rewrite::{append_child, rewrite_non_terminal};
+
+/// Lists whose members carry their own `,` need no separator between them
+static BRACES: Delimiters<'static> = Delimiters::new("{", "}");
+
+pub(super) const KINDS: &[&str] = &["field_declaration_list", "enum_variant_list", "use_list"];
+
+/// Lists formatted one member per line end every member with `,`, including the last one. When
+/// that is the case, each `,` is moved into its member, so merges keep the trailing one. Lists
+/// with no trailing `,` keep them as separators, placed between members only.
+pub fn attach_trailing_commas(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::NonTerminal(list) if KINDS.contains(&list.kind) && ends_every_member(&list) => {
+            attach(list)
+        }
+        _ => root,
+    }
+}
+
+fn is_comma(node: &CSTNode) -> bool {
+    matches!(node, CSTNode::Terminal(terminal) if terminal.kind == ",")
+}
+
+fn ends_every_member(list: &NonTerminal) -> bool {
+    let members: Vec<(usize, &CSTNode)> = list
+        .children
+        .iter()
+        .enumerate()
+        .filter(|(_, child)| !matches!(child.kind(), "{" | "}" | ","))
+        .collect();
+
+    !members.is_empty()
+        && members.iter().all(|(index, member)| {
+            !member.is_terminal() && list.children.get(index + 1).is_some_and(is_comma)
+        })
+}
+
+fn attach(list: NonTerminal<'_>) -> CSTNode<'_> {
+    rewrite_non_terminal(list, |list| {
+        let mut attached_children: Vec<CSTNode> = Vec::with_capacity(list.children.len());
+        for child in std::mem::take(&mut list.children) {
+            match attached_children.pop() {
+                Some(CSTNode::NonTerminal(member)) if is_comma(&child) => {
+                    attached_children.push(append_child(member, child))
+                }
+                last => {
+                    attached_children.extend(last);
+                    attached_children.push(child);
+                }
+            }
+        }
+        list.children = attached_children;
+        list.delimiters = Some(&BRACES);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::attach_trailing_commas;
+
+    fn terminal(kind: &'static str) -> CSTNode<'static> {
+        CSTNode::Terminal(Terminal {
+            kind,
+            value: kind,
+            ..Default::default()
+        })
+    }
+
+    fn field() -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "field_declaration",
+            children: vec![terminal("field_identifier")],
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn it_moves_commas_into_members_when_the_last_member_has_one() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "field_declaration_list",
+            children: vec![
+                terminal("{"),
+                field(),
+                terminal(","),
+                field(),
+                terminal(","),
+                terminal("}"),
+            ],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(list) = attach_trailing_commas(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        assert_eq!(list.children.len(), 4);
+        assert!(list.delimiters.unwrap().separator().is_none());
+        let CSTNode::NonTerminal(last_field) = &list.children[2] else {
+            panic!("Expected the field to be a non terminal");
+        };
+        assert_eq!(last_field.children.last().unwrap().kind(), ",");
+    }
+
+    #[test]
+    fn it_leaves_lists_with_no_trailing_comma_alone() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "enum_variant_list",
+            children: vec![
+                terminal("{"),
+                field(),
+                terminal(","),
+                field(),
+                terminal("}"),
+            ],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(list) = attach_trailing_commas(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        assert_eq!(list.children.len(), 5);
+        assert!(list.delimiters.is_none());
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{parsing_handlers::parsing_handlers, GroupSiblings, ParsingHandlers, SiblingKinds};
+
+mod attach_attributes;
+mod attach_trailing_commas;
+
+/// Unlike other items, `use` declarations are not identified by a name, so grouping them lets
+/// the ones added by each side be merged apart from the rest of the items
+const USE_DECLARATIONS: GroupSiblings = GroupSiblings {
+    name: "tweak_use_declarations",
+    parent_kinds: &["source_file", "declaration_list"],
+    sibling_kinds: SiblingKinds::Only(&["use_declaration"]),
+    group_kind: "use_declarations",
+    delimiters: None,
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: false,
+};
+
+pub fn get_default_rust_parsing_handlers() -> ParsingHandlers {
+    let mut handlers = parsing_handlers![
+        attach_attributes::attach_attributes => attach_attributes::KINDS,
+        attach_trailing_commas::attach_trailing_commas => attach_trailing_commas::KINDS,
+    ];
+    handlers.push(USE_DECLARATIONS);
+    handlers
+}
@@ -0,0 +1,20 @@
+use crate::{GroupSiblings, ParsingHandlers, SiblingKinds};
+
+/// The TOML grammar places the key/value pairs of a table right after its header. Grouping them
+/// keeps the header in its place while the pairs are merged by key, and keeps the pairs before
+/// the first table ahead of every table.
+const PAIRS: GroupSiblings = GroupSiblings {
+    name: "tweak_pairs",
+    parent_kinds: &["document", "table", "table_array_element"],
+    sibling_kinds: SiblingKinds::Only(&["pair"]),
+    group_kind: "pairs",
+    delimiters: None,
+    are_children_unordered: true,
+    are_children_aligned: false,
+    leading_white_space: None,
+    first_run_only: true,
+};
+
+pub fn get_default_toml_parsing_handlers() -> ParsingHandlers {
+    ParsingHandlers::new(vec![Box::new(PAIRS)])
+}
@@ -0,0 +1,143 @@
+use model::{
+    cst_node::{Delimiters, NonTerminal},
+    CSTNode,
+};
+
+use crate::rewrite::{append_child, rewrite_non_terminal};
+
+/// Object types terminated by `;` need no separator between their members
+static BRACES: Delimiters<'static> = Delimiters::new("{", "}");
+
+pub(super) const KINDS: &[&str] = &["class_body", "interface_body", "object_type"];
+
+/// In TypeScript, the `;` ending a class or interface member is a sibling of the member, not a
+/// part of it. Moving it into the member keeps both together when unordered bodies are merged.
+/// The `,` in object types and interfaces are left alone, as they are taken as separators.
+pub fn attach_member_terminators(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::NonTerminal(body) if body.kind == "class_body" => attach(body, &[";", ","]),
+        CSTNode::NonTerminal(body)
+            if body.kind == "interface_body" || body.kind == "object_type" =>
+        {
+            attach(body, &[";"])
+        }
+        _ => root,
+    }
+}
+
+fn attach<'a>(body: NonTerminal<'a>, terminators: &[&str]) -> CSTNode<'a> {
+    rewrite_non_terminal(body, |body| {
+        let mut attached_children: Vec<CSTNode<'a>> = Vec::with_capacity(body.children.len());
+        let mut attached_any = false;
+        for child in std::mem::take(&mut body.children) {
+            let is_terminator = matches!(
+                &child,
+                CSTNode::Terminal(terminal) if terminators.contains(&terminal.kind)
+            );
+            match attached_children.pop() {
+                Some(CSTNode::NonTerminal(member)) if is_terminator => {
+                    attached_children.push(append_child(member, child));
+                    attached_any = true;
+                }
+                last => {
+                    attached_children.extend(last);
+                    attached_children.push(child);
+                }
+            }
+        }
+        body.children = attached_children;
+
+        if attached_any
+            && body
+                .delimiters
+                .is_some_and(|delimiters| delimiters.separator().is_some())
+        {
+            body.delimiters = Some(&BRACES);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode, Point,
+    };
+
+    use super::attach_member_terminators;
+
+    fn terminal(kind: &'static str, column: usize) -> CSTNode<'static> {
+        CSTNode::Terminal(Terminal {
+            kind,
+            value: kind,
+            start_position: Point { row: 0, column },
+            end_position: Point {
+                row: 0,
+                column: column + 1,
+            },
+            ..Default::default()
+        })
+    }
+
+    fn member(kind: &'static str, column: usize) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind,
+            children: vec![terminal("property_identifier", column)],
+            start_position: Point { row: 0, column },
+            end_position: Point {
+                row: 0,
+                column: column + 1,
+            },
+            ..Default::default()
+        })
+    }
+
+    fn body(kind: &'static str, children: Vec<CSTNode<'static>>) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind,
+            children,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn it_moves_the_semicolon_after_a_member_into_it() {
+        let root = body(
+            "class_body",
+            vec![
+                terminal("{", 0),
+                member("public_field_definition", 1),
+                terminal(";", 2),
+                terminal("}", 3),
+            ],
+        );
+
+        let CSTNode::NonTerminal(result) = attach_member_terminators(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        assert_eq!(result.children.len(), 3);
+        let CSTNode::NonTerminal(field) = &result.children[1] else {
+            panic!("Expected the field to be a non terminal");
+        };
+        assert_eq!(field.children.last().map(|child| child.kind()), Some(";"));
+        assert_eq!(field.end_position, Point { row: 0, column: 3 });
+    }
+
+    #[test]
+    fn it_keeps_commas_of_object_types_as_separators() {
+        let children = vec![
+            terminal("{", 0),
+            member("property_signature", 1),
+            terminal(",", 2),
+            member("property_signature", 3),
+            terminal("}", 4),
+        ];
+        let root = body("object_type", children.clone());
+
+        assert_eq!(
+            attach_member_terminators(root),
+            body("object_type", children)
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use crate::{parsing_handlers::parsing_handlers, ParsingHandlers};
+
+mod attach_member_terminators;
+
+pub fn get_default_typescript_parsing_handlers() -> ParsingHandlers {
+    parsing_handlers![
+        attach_member_terminators::attach_member_terminators => attach_member_terminators::KINDS
+    ]
+}
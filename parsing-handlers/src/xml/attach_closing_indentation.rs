@@ -0,0 +1,126 @@
+use model::CSTNode;
+
+use crate::rewrite::rewrite_non_terminal;
+
+pub(super) const KINDS: &[&str] = &["element"];
+
+/// White space between elements is kept by the XML grammar as character data. Most of it is
+/// carried by the next element as its leading white space, but the one before a closing tag is
+/// the last child of the element content. This moves it to the closing tag, so elements added to the content are placed
+/// before it instead of after it.
+pub fn attach_closing_indentation(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::NonTerminal(element) if KINDS.contains(&element.kind) => {
+            rewrite_non_terminal(element, |element| {
+                if let [_, CSTNode::NonTerminal(content), CSTNode::NonTerminal(closing_tag)] =
+                    element.children.as_mut_slice()
+                {
+                    if let Some(indentation) = content.children.last().and_then(closing_indentation)
+                    {
+                        if closing_tag
+                            .leading_white_space
+                            .unwrap_or_default()
+                            .is_empty()
+                        {
+                            closing_tag.leading_white_space = Some(indentation);
+                            element.children[1] =
+                                rewrite_non_terminal(std::mem::take(content), |content| {
+                                    content.children.pop();
+                                });
+                        }
+                    }
+                }
+            })
+        }
+        _ => root,
+    }
+}
+
+fn closing_indentation<'a>(node: &CSTNode<'a>) -> Option<&'a str> {
+    match node {
+        CSTNode::Terminal(terminal)
+            if terminal.kind == "CharData"
+                && terminal.value.trim().is_empty()
+                && terminal.leading_white_space.unwrap_or_default().is_empty() =>
+        {
+            Some(terminal.value)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::attach_closing_indentation;
+
+    fn char_data(value: &'static str) -> CSTNode<'static> {
+        CSTNode::Terminal(Terminal {
+            kind: "CharData",
+            value,
+            ..Default::default()
+        })
+    }
+
+    fn element(content: Vec<CSTNode<'static>>) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "element",
+            children: vec![
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "STag",
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "content",
+                    children: content,
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "ETag",
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn it_moves_the_indentation_before_the_closing_tag_to_it() {
+        let root = element(vec![
+            CSTNode::NonTerminal(NonTerminal {
+                kind: "element",
+                ..Default::default()
+            }),
+            char_data("\n  "),
+        ]);
+
+        let CSTNode::NonTerminal(element) = attach_closing_indentation(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        let CSTNode::NonTerminal(content) = &element.children[1] else {
+            panic!("Expected the content to be a non terminal");
+        };
+        assert_eq!(content.children.len(), 1);
+        assert_eq!(element.children[2].leading_white_space(), Some("\n  "));
+    }
+
+    #[test]
+    fn it_keeps_text_content_in_place() {
+        let root = element(vec![char_data("junit")]);
+
+        let CSTNode::NonTerminal(element) = attach_closing_indentation(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        let CSTNode::NonTerminal(content) = &element.children[1] else {
+            panic!("Expected the content to be a non terminal");
+        };
+        assert_eq!(content.children.len(), 1);
+        assert_eq!(element.children[2].leading_white_space(), None);
+    }
+}
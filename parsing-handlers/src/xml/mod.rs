@@ -0,0 +1,14 @@
+use crate::{parsing_handlers::parsing_handlers, ParsingHandlers};
+
+mod attach_closing_indentation;
+mod tweak_unordered_elements;
+
+pub use tweak_unordered_elements::{UnorderedElements, MAVEN_COLLECTIONS};
+
+pub fn get_default_xml_parsing_handlers() -> ParsingHandlers {
+    let mut handlers = parsing_handlers![
+        attach_closing_indentation::attach_closing_indentation => attach_closing_indentation::KINDS
+    ];
+    handlers.push(UnorderedElements::default());
+    handlers
+}
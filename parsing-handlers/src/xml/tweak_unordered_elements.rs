@@ -0,0 +1,141 @@
+use model::CSTNode;
+
+use crate::{rewrite::rewrite_non_terminal, ParsingHandler};
+
+/// Elements whose children are merged unordered by default. XML is ordered in general, but these
+/// are collections in Maven build files, whose entries are told apart by their identifiers.
+pub const MAVEN_COLLECTIONS: &[&str] = &[
+    "dependencies",
+    "exclusions",
+    "modules",
+    "pluginRepositories",
+    "plugins",
+    "profiles",
+    "properties",
+    "repositories",
+];
+
+/// Makes the content of the elements with the given names unordered. Every XML element shares the
+/// same `content` node kind, so the unordered ones are told apart by the name in their start tag
+/// instead.
+#[derive(Debug, Clone)]
+pub struct UnorderedElements {
+    /// The name the handler is enabled by in the configuration of a language
+    pub name: &'static str,
+    /// Names of the elements whose children are merged unordered
+    pub elements: Vec<&'static str>,
+}
+
+impl Default for UnorderedElements {
+    fn default() -> Self {
+        Self {
+            name: "tweak_unordered_elements",
+            elements: MAVEN_COLLECTIONS.to_vec(),
+        }
+    }
+}
+
+impl ParsingHandler for UnorderedElements {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn kinds(&self) -> Option<&[&str]> {
+        Some(&["element"])
+    }
+
+    fn handle<'a>(&self, node: CSTNode<'a>) -> CSTNode<'a> {
+        let CSTNode::NonTerminal(element) = node else {
+            return node;
+        };
+
+        rewrite_non_terminal(element, |element| {
+            if let [CSTNode::NonTerminal(start_tag), CSTNode::NonTerminal(content), ..] =
+                element.children.as_mut_slice()
+            {
+                let is_unordered = start_tag
+                    .children
+                    .iter()
+                    .find(|child| child.kind() == "Name")
+                    .is_some_and(|name| self.elements.contains(&name.contents().as_str()));
+                if start_tag.kind == "STag" && is_unordered {
+                    content.are_children_unordered = true;
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::UnorderedElements;
+    use crate::ParsingHandler;
+
+    fn element(name: &'static str) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "element",
+            children: vec![
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "STag",
+                    children: vec![CSTNode::Terminal(Terminal {
+                        kind: "Name",
+                        value: name,
+                        ..Default::default()
+                    })],
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "content",
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "ETag",
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+
+    fn is_content_unordered(element: CSTNode) -> bool {
+        let CSTNode::NonTerminal(element) = element else {
+            panic!("Expected a non terminal");
+        };
+        let CSTNode::NonTerminal(content) = &element.children[1] else {
+            panic!("Expected the content to be a non terminal");
+        };
+        content.are_children_unordered
+    }
+
+    #[test]
+    fn it_makes_the_content_of_collection_elements_unordered() {
+        assert!(is_content_unordered(
+            UnorderedElements::default().handle(element("dependencies"))
+        ));
+    }
+
+    #[test]
+    fn it_keeps_the_content_of_other_elements_ordered() {
+        assert!(!is_content_unordered(
+            UnorderedElements::default().handle(element("build"))
+        ));
+    }
+
+    #[test]
+    fn it_makes_the_content_of_the_configured_elements_unordered() {
+        let handler = UnorderedElements {
+            elements: vec!["executions"],
+            ..Default::default()
+        };
+
+        assert!(is_content_unordered(handler.handle(element("executions"))));
+        assert!(!is_content_unordered(
+            handler.handle(element("dependencies"))
+        ));
+    }
+}
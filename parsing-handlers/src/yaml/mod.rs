@@ -0,0 +1,7 @@
+use crate::{parsing_handlers::parsing_handlers, ParsingHandlers};
+
+mod tweak_named_sequences;
+
+pub fn get_default_yaml_parsing_handlers() -> ParsingHandlers {
+    parsing_handlers![tweak_named_sequences::tweak_named_sequences]
+}
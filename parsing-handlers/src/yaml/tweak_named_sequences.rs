@@ -0,0 +1,76 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+use crate::rewrite::rewrite_non_terminal;
+
+/// Sequences are ordered in YAML, but lists such as containers, environment variables or ports
+/// are made of mappings told apart by their `name` field. Those items are given the name as
+/// identifier while parsing, so a sequence whose items all have one is merged unordered.
+pub fn tweak_named_sequences(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::NonTerminal(sequence)
+            if sequence.kind == "block_sequence" && has_only_named_items(&sequence) =>
+        {
+            rewrite_non_terminal(sequence, |sequence| sequence.are_children_unordered = true)
+        }
+        _ => root,
+    }
+}
+
+fn has_only_named_items(sequence: &NonTerminal) -> bool {
+    let mut items = sequence
+        .children
+        .iter()
+        .filter(|child| child.kind() == "block_sequence_item")
+        .peekable();
+
+    items.peek().is_some()
+        && items.all(|item| match item {
+            CSTNode::NonTerminal(item) => item.identifier.is_some(),
+            CSTNode::Terminal(_) => false,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{cst_node::NonTerminal, CSTNode};
+
+    use super::tweak_named_sequences;
+
+    fn item(name: Option<&'static str>) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "block_sequence_item",
+            identifier: name.map(|name| vec!["name", name]),
+            ..Default::default()
+        })
+    }
+
+    fn sequence(children: Vec<CSTNode<'static>>) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "block_sequence",
+            children,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn it_makes_sequences_of_named_items_unordered() {
+        let root = sequence(vec![item(Some("nginx")), item(Some("sidecar"))]);
+
+        let CSTNode::NonTerminal(sequence) = tweak_named_sequences(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        assert!(sequence.are_children_unordered);
+    }
+
+    #[test]
+    fn it_keeps_sequences_with_an_unnamed_item_ordered() {
+        let root = sequence(vec![item(Some("nginx")), item(None)]);
+
+        let CSTNode::NonTerminal(sequence) = tweak_named_sequences(root) else {
+            panic!("Expected a non terminal");
+        };
+
+        assert!(!sequence.are_children_unordered);
+    }
+}
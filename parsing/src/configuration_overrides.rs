@@ -0,0 +1,80 @@
+use parsing_handlers::UnorderedElements;
+use serde::Deserialize;
+
+use crate::configuration_overrides_error::ConfigurationOverridesError;
+use crate::language_definition::leak;
+use crate::tree_sitter_parser::ParserConfiguration;
+
+/// Adjustments to the configuration of a language, such as the ones a repository keeps for the
+/// languages it is written in
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigurationOverrides {
+    /// Kinds whose children are merged regardless of their order, besides the language's own
+    #[serde(default)]
+    pub kinds_with_unordered_children: Vec<String>,
+    /// Kinds merged as a single piece of text, besides the language's own
+    #[serde(default)]
+    pub stop_compilation_at: Vec<String>,
+    /// Names of the parsing handlers to run. All of the language's handlers run if not given.
+    #[serde(default)]
+    pub handlers: Option<Vec<String>>,
+    /// Names of XML elements whose children are merged regardless of their order, besides the
+    /// Maven collections
+    #[serde(default)]
+    pub unordered_elements: Vec<String>,
+}
+
+impl ParserConfiguration {
+    pub fn apply_overrides(
+        &mut self,
+        overrides: &ConfigurationOverrides,
+    ) -> Result<(), ConfigurationOverridesError> {
+        // Only XML has the handler the unordered elements are given to
+        let unordered_elements_handler = UnorderedElements::default().name;
+        if !overrides.unordered_elements.is_empty()
+            && !self
+                .handlers
+                .names()
+                .any(|name| name == unordered_elements_handler)
+        {
+            return Err(ConfigurationOverridesError::UnorderedElementsOutsideXml);
+        }
+
+        if let Some(handlers) = &overrides.handlers {
+            if let Some(unknown) = handlers
+                .iter()
+                .find(|name| !self.handlers.names().any(|known| known == name.as_str()))
+            {
+                return Err(ConfigurationOverridesError::UnknownHandler(unknown.clone()));
+            }
+            self.handlers
+                .retain(|name| handlers.iter().any(|enabled| enabled == name));
+        }
+
+        self.kinds_with_unordered_children.extend(
+            overrides
+                .kinds_with_unordered_children
+                .iter()
+                .map(|kind| leak(kind)),
+        );
+        self.stop_compilation_at
+            .extend(overrides.stop_compilation_at.iter().map(|kind| leak(kind)));
+        // Left out along with the handler if the overrides disable it
+        let is_unordered_elements_handler_enabled = self
+            .handlers
+            .names()
+            .any(|name| name == unordered_elements_handler);
+        if !overrides.unordered_elements.is_empty() && is_unordered_elements_handler_enabled {
+            self.handlers.push(UnorderedElements {
+                elements: overrides
+                    .unordered_elements
+                    .iter()
+                    .map(|name| leak(name))
+                    .collect(),
+                ..Default::default()
+            });
+        }
+        Ok(())
+    }
+}
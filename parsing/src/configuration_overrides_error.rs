@@ -0,0 +1,26 @@
+use std::error;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ConfigurationOverridesError {
+    UnknownHandler(String),
+    UnorderedElementsOutsideXml,
+}
+
+impl fmt::Display for ConfigurationOverridesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigurationOverridesError::UnknownHandler(name) => {
+                write!(
+                    f,
+                    "There is no parsing handler named {name} for this language"
+                )
+            }
+            ConfigurationOverridesError::UnorderedElementsOutsideXml => {
+                write!(f, "Unordered elements can only be given for XML")
+            }
+        }
+    }
+}
+
+impl error::Error for ConfigurationOverridesError {}
@@ -0,0 +1,51 @@
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use tree_sitter_language::LanguageFn;
+
+use crate::language_definition_error::LanguageDefinitionError;
+
+/// Loads a tree-sitter grammar compiled as a shared library, calling the function named `symbol`
+/// that tree-sitter generates for it, such as `tree_sitter_scala`.
+///
+/// Each library is loaded once and never unloaded, as the grammar and every tree parsed with it
+/// point into it.
+pub fn load_grammar(
+    path: &Path,
+    symbol: &str,
+) -> Result<tree_sitter::Language, LanguageDefinitionError> {
+    static LIBRARIES: Mutex<BTreeMap<PathBuf, libloading::Library>> = Mutex::new(BTreeMap::new());
+
+    let mut libraries = LIBRARIES.lock().unwrap_or_else(|error| error.into_inner());
+    let library = match libraries.entry(path.to_path_buf()) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        // Safety: loading a library runs its initialization code, so only libraries the user
+        // pointed at are loaded. Grammars generated by the tree-sitter CLI have none.
+        Entry::Vacant(entry) => entry.insert(
+            unsafe { libloading::Library::new(path) }
+                .map_err(LanguageDefinitionError::LoadingGrammar)?,
+        ),
+    };
+
+    // Safety: the symbol is expected to be a grammar function generated by the tree-sitter CLI,
+    // which takes no arguments and returns a pointer to the grammar.
+    let language_fn = unsafe {
+        library
+            .get::<unsafe extern "C" fn() -> *const ()>(symbol.as_bytes())
+            .map(|function| LanguageFn::from_raw(*function))
+    }
+    .map_err(LanguageDefinitionError::LoadingGrammar)?;
+
+    let language = tree_sitter::Language::new(language_fn);
+    if !(tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION..=tree_sitter::LANGUAGE_VERSION)
+        .contains(&language.abi_version())
+    {
+        return Err(LanguageDefinitionError::IncompatibleGrammar(
+            language.abi_version(),
+        ));
+    }
+    Ok(language)
+}
@@ -1,4 +1,4 @@
-use tree_sitter::{Language, Node, Query, QueryCursor, StreamingIterator};
+use tree_sitter::{Language, Node, Query, QueryCursor, QueryError, StreamingIterator};
 
 pub trait IdentifierExtractor {
     fn extract_identifier_from_node<'a>(&self, node: Node, src: &'a str) -> Option<Vec<&'a str>>;
@@ -8,18 +8,23 @@ pub struct TreeSitterQuery(Query);
 
 impl TreeSitterQuery {
     pub fn new(query: &'static str, language: Language) -> Self {
-        Self(
-            Query::new(&language, query)
-                .expect("Invalid Query provided for building TreeSitterQuery"),
-        )
+        Self::try_new(query, language).expect("Invalid Query provided for building TreeSitterQuery")
     }
-}
 
-impl IdentifierExtractor for TreeSitterQuery {
-    fn extract_identifier_from_node<'a>(&self, node: Node, src: &'a str) -> Option<Vec<&'a str>> {
+    pub fn try_new(query: &str, language: Language) -> Result<Self, QueryError> {
+        Query::new(&language, query).map(Self)
+    }
+
+    fn first_match<'a>(
+        &self,
+        node: Node,
+        src: &'a str,
+        max_start_depth: Option<u32>,
+    ) -> Option<Vec<&'a str>> {
         let mut cursor = QueryCursor::new();
         cursor
             .set_byte_range(node.byte_range())
+            .set_max_start_depth(max_start_depth)
             .matches(&self.0, node, src.as_bytes())
             .next()
             .map(|a_match| {
@@ -31,3 +36,64 @@ impl IdentifierExtractor for TreeSitterQuery {
             })
     }
 }
+
+impl IdentifierExtractor for TreeSitterQuery {
+    /// Matches rooted at `node` itself are preferred, as the ones of its descendants may be
+    /// found first. Queries whose patterns start below `node` are searched for anywhere within it.
+    fn extract_identifier_from_node<'a>(&self, node: Node, src: &'a str) -> Option<Vec<&'a str>> {
+        self.first_match(node, src, Some(0))
+            .or_else(|| self.first_match(node, src, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter::Parser;
+
+    use super::{IdentifierExtractor, TreeSitterQuery};
+
+    fn extract(language: tree_sitter::Language, query: &'static str, src: &str) -> Vec<String> {
+        let mut parser = Parser::new();
+        parser.set_language(&language).unwrap();
+        let tree = parser.parse(src, None).unwrap();
+        let node = tree.root_node().named_child(0).unwrap();
+
+        TreeSitterQuery::new(query, language)
+            .extract_identifier_from_node(node, src)
+            .unwrap_or_default()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    // Matches are yielded as they complete, so the one of a nested element, such as `<groupId>`,
+    // is found before the one of the element it is in
+    #[test]
+    fn matches_rooted_at_the_node_are_preferred_to_the_ones_of_its_descendants() {
+        let query = r#"
+            (element (STag (Name) @tag) (content (element (STag (Name) @group_tag (#eq? @group_tag "groupId")) (content) @group) (element (STag (Name) @artifact_tag (#eq? @artifact_tag "artifactId")) (content) @artifact)))
+            (element (STag (Name) @tag) . (content . (CharData) .))
+        "#;
+
+        assert_eq!(
+            extract(
+                tree_sitter_xml::LANGUAGE_XML.into(),
+                query,
+                "<dependency><groupId>g</groupId><artifactId>a</artifactId></dependency>"
+            ),
+            ["dependency", "groupId", "g", "artifactId", "a"]
+        );
+    }
+
+    #[test]
+    fn matches_below_the_node_are_used_when_none_is_rooted_at_it() {
+        assert_eq!(
+            extract(
+                tree_sitter_java::LANGUAGE.into(),
+                "(identifier) @name",
+                "class Main {}"
+            ),
+            ["Main"]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+use model::{cst_node::Delimiters, Language};
+use parsing_handlers::ParsingHandlers;
+use serde::Deserialize;
+
+use crate::dynamic_grammar::load_grammar;
+use crate::identifier_extractor::{IdentifierExtractor, TreeSitterQuery};
+use crate::language_definition_error::LanguageDefinitionError;
+use crate::tree_sitter_parser::{built_in_grammar, ParserConfiguration};
+
+/// A language described in a TOML or JSON file instead of in code.
+///
+/// It reuses the grammar of one of the built-in languages, along with its parsing handlers, or
+/// loads one compiled as a shared library. Everything else that drives the merge is described:
+/// which nodes are compiled as terminals, which have unordered children, which are trivia, their
+/// delimiters and how their identifiers are extracted.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LanguageDefinition {
+    pub name: String,
+    /// The name of the built-in language whose grammar is used, such as "java", or the name of
+    /// the grammar found in `library`
+    pub grammar: String,
+    /// Shared library to load the grammar from, for grammars that are not built in. Relative
+    /// paths are resolved from the directory of the definition file.
+    #[serde(default)]
+    pub library: Option<PathBuf>,
+    /// Function of `library` returning the grammar, `tree_sitter_<grammar>` by default
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// Extensions of the files this definition applies to, without the leading dot
+    #[serde(default)]
+    pub extensions: Vec<String>,
+    #[serde(default)]
+    pub stop_compilation_at: Vec<String>,
+    #[serde(default)]
+    pub kinds_with_unordered_children: Vec<String>,
+    #[serde(default)]
+    pub trivia_kinds: Vec<String>,
+    #[serde(default)]
+    pub white_space_kinds: Vec<String>,
+    #[serde(default)]
+    pub delimiters: HashMap<String, DelimitersDefinition>,
+    /// Tree-sitter queries by node kind. The captures of the first match are the identifier.
+    #[serde(default)]
+    pub identifier_queries: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DelimitersDefinition {
+    pub start: String,
+    pub end: String,
+    #[serde(default)]
+    pub separator: Option<String>,
+}
+
+impl LanguageDefinition {
+    pub fn from_toml(src: &str) -> Result<Self, LanguageDefinitionError> {
+        toml::from_str(src).map_err(LanguageDefinitionError::InvalidToml)
+    }
+
+    pub fn from_json(src: &str) -> Result<Self, LanguageDefinitionError> {
+        serde_json::from_str(src).map_err(LanguageDefinitionError::InvalidJson)
+    }
+
+    /// Reads a definition from `path`, as JSON if it has a `.json` extension and as TOML otherwise
+    pub fn from_file(path: &Path) -> Result<Self, LanguageDefinitionError> {
+        let src = std::fs::read_to_string(path).map_err(LanguageDefinitionError::Reading)?;
+        let mut definition = match path.extension().and_then(std::ffi::OsStr::to_str) {
+            Some("json") => Self::from_json(&src),
+            _ => Self::from_toml(&src),
+        }?;
+
+        if let (Some(library), Some(directory)) = (&definition.library, path.parent()) {
+            definition.library = Some(directory.join(library));
+        }
+        Ok(definition)
+    }
+
+    /// The built-in language whose grammar and parsing handlers are used, unless the grammar is
+    /// loaded from a library
+    pub fn built_in_grammar(&self) -> Option<Language> {
+        match self.library {
+            Some(_) => None,
+            None => self.grammar.parse().ok(),
+        }
+    }
+
+    /// Whether `file_path` has one of the definition's extensions. A definition without
+    /// extensions applies to every file.
+    pub fn applies_to(&self, file_path: &Path) -> bool {
+        self.extensions.is_empty()
+            || file_path
+                .extension()
+                .and_then(std::ffi::OsStr::to_str)
+                .is_some_and(|extension| self.extensions.iter().any(|known| known == extension))
+    }
+}
+
+/// Node kinds live as long as the configuration's trees, which may outlive the definition, so
+/// they are leaked instead of being tied to it. Each distinct string is leaked once, however many
+/// configurations are built from definitions or overrides.
+pub(crate) fn leak(value: &str) -> &'static str {
+    static LEAKED: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+    let mut leaked = LEAKED.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(existing) = leaked.get(value) {
+        return existing;
+    }
+    let value: &'static str = value.to_owned().leak();
+    leaked.insert(value);
+    value
+}
+
+impl TryFrom<&LanguageDefinition> for ParserConfiguration {
+    type Error = LanguageDefinitionError;
+
+    fn try_from(definition: &LanguageDefinition) -> Result<Self, Self::Error> {
+        let (language, handlers) = match (&definition.library, definition.built_in_grammar()) {
+            (Some(library), _) => {
+                let symbol = definition
+                    .symbol
+                    .clone()
+                    .unwrap_or_else(|| format!("tree_sitter_{}", definition.grammar));
+                (load_grammar(library, &symbol)?, ParsingHandlers::empty())
+            }
+            (None, Some(grammar)) => (built_in_grammar(grammar), ParsingHandlers::from(grammar)),
+            (None, None) => {
+                return Err(LanguageDefinitionError::UnknownGrammar(
+                    definition.grammar.clone(),
+                ))
+            }
+        };
+
+        let identifier_extractors = definition
+            .identifier_queries
+            .iter()
+            .map(|(kind, query)| {
+                TreeSitterQuery::try_new(query, language.clone())
+                    .map(|query| (leak(kind), Box::new(query) as Box<dyn IdentifierExtractor>))
+                    .map_err(|error| LanguageDefinitionError::InvalidQuery {
+                        kind: kind.clone(),
+                        error,
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let delimiters = definition
+            .delimiters
+            .iter()
+            .map(|(kind, delimiters)| {
+                let (start, end) = (leak(&delimiters.start), leak(&delimiters.end));
+                let delimiters = match &delimiters.separator {
+                    Some(separator) => Delimiters::new_with_separator(start, end, leak(separator)),
+                    None => Delimiters::new(start, end),
+                };
+                (leak(kind), delimiters)
+            })
+            .collect();
+
+        Ok(ParserConfiguration {
+            language,
+            stop_compilation_at: definition
+                .stop_compilation_at
+                .iter()
+                .map(|kind| leak(kind))
+                .collect(),
+            kinds_with_unordered_children: definition
+                .kinds_with_unordered_children
+                .iter()
+                .map(|kind| leak(kind))
+                .collect(),
+            trivia_kinds: definition
+                .trivia_kinds
+                .iter()
+                .map(|kind| leak(kind))
+                .collect(),
+            white_space_kinds: definition
+                .white_space_kinds
+                .iter()
+                .map(|kind| leak(kind))
+                .collect(),
+            delimiters,
+            handlers,
+            identifier_extractors,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::leak;
+
+    #[test]
+    fn each_string_is_leaked_once() {
+        let first = leak("leaked_once");
+        let second = leak(&String::from("leaked_once"));
+
+        assert!(std::ptr::eq(first, second));
+    }
+}
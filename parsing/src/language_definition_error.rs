@@ -0,0 +1,59 @@
+use std::error;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum LanguageDefinitionError {
+    Reading(std::io::Error),
+    InvalidToml(toml::de::Error),
+    InvalidJson(serde_json::Error),
+    UnknownGrammar(String),
+    LoadingGrammar(libloading::Error),
+    IncompatibleGrammar(usize),
+    InvalidQuery {
+        kind: String,
+        error: tree_sitter::QueryError,
+    },
+}
+
+impl fmt::Display for LanguageDefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LanguageDefinitionError::Reading(error) => {
+                write!(f, "Could not read the language definition: {error}")
+            }
+            LanguageDefinitionError::InvalidToml(error) => {
+                write!(f, "The language definition is not valid TOML: {error}")
+            }
+            LanguageDefinitionError::InvalidJson(error) => {
+                write!(f, "The language definition is not valid JSON: {error}")
+            }
+            LanguageDefinitionError::UnknownGrammar(grammar) => {
+                write!(f, "There is no built-in grammar named {grammar}")
+            }
+            LanguageDefinitionError::LoadingGrammar(error) => {
+                write!(f, "Could not load the grammar library: {error}")
+            }
+            LanguageDefinitionError::IncompatibleGrammar(version) => write!(
+                f,
+                "The grammar uses version {version} of the tree-sitter ABI, which is not supported"
+            ),
+            LanguageDefinitionError::InvalidQuery { kind, error } => {
+                write!(f, "The identifier query for {kind} is not valid: {error}")
+            }
+        }
+    }
+}
+
+impl error::Error for LanguageDefinitionError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            LanguageDefinitionError::Reading(error) => Some(error),
+            LanguageDefinitionError::InvalidToml(error) => Some(error),
+            LanguageDefinitionError::InvalidJson(error) => Some(error),
+            LanguageDefinitionError::UnknownGrammar(_) => None,
+            LanguageDefinitionError::LoadingGrammar(error) => Some(error),
+            LanguageDefinitionError::IncompatibleGrammar(_) => None,
+            LanguageDefinitionError::InvalidQuery { error, .. } => Some(error),
+        }
+    }
+}
@@ -1,7 +1,25 @@
+mod configuration_overrides;
+mod configuration_overrides_error;
+mod dynamic_grammar;
 mod identifier_extractor;
+mod language_definition;
+mod language_definition_error;
 mod macros;
 mod parse;
+mod parse_error;
+mod syntax_diagnostic;
 mod tree_sitter_parser;
 
-pub use parse::parse_string;
+pub use configuration_overrides::ConfigurationOverrides;
+pub use configuration_overrides_error::ConfigurationOverridesError;
+pub use dynamic_grammar::load_grammar;
+pub use language_definition::{DelimitersDefinition, LanguageDefinition};
+pub use language_definition_error::LanguageDefinitionError;
+pub use parse::{parse_string, ParsedTree};
+pub use parse_error::ParseError;
+pub use parsing_handlers::{
+    group_nodes, rewrite_non_terminal, FnParsingHandler, GroupSiblings, ParsingHandler,
+    ParsingHandlers, SiblingKinds, UnorderedElements, MAVEN_COLLECTIONS,
+};
+pub use syntax_diagnostic::{SyntaxDiagnostic, SyntaxDiagnosticKind};
 pub use tree_sitter_parser::ParserConfiguration;
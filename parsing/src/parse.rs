@@ -1,5 +1,7 @@
 use std::cell::OnceCell;
 
+use crate::parse_error::ParseError;
+use crate::syntax_diagnostic::{collect_syntax_diagnostics, SyntaxDiagnostic};
 use crate::tree_sitter_parser::ParserConfiguration;
 use model::{
     cst_node::{NonTerminal, Terminal},
@@ -7,7 +9,12 @@ use model::{
 };
 use tree_sitter::Node;
 
-fn explore_node<'a>(node: Node, src: &'a str, config: &'a ParserConfiguration) -> CSTNode<'a> {
+fn explore_node<'a>(
+    node: Node,
+    src: &'a str,
+    config: &'a ParserConfiguration,
+    leading_white_space: Option<&'a str>,
+) -> CSTNode<'a> {
     if node.child_count() == 0 || config.stop_compilation_at.contains(node.kind()) {
         CSTNode::Terminal(Terminal {
             id: uuid::Uuid::new_v4(),
@@ -21,12 +28,9 @@ fn explore_node<'a>(node: Node, src: &'a str, config: &'a ParserConfiguration) -
                 column: node.end_position().column,
             },
             value: &src[node.byte_range()],
-            leading_white_space: node
-                .prev_sibling()
-                .map(|previous| &src[previous.end_byte()..node.start_byte()]),
+            leading_white_space,
         })
     } else {
-        let mut cursor = node.walk();
         let identifier = config
             .identifier_extractors
             .get(node.kind())
@@ -47,15 +51,11 @@ fn explore_node<'a>(node: Node, src: &'a str, config: &'a ParserConfiguration) -
                 row: node.end_position().row,
                 column: node.end_position().column,
             },
-            children: node
-                .children(&mut cursor)
-                .map(|child| config.handlers.run(explore_node(child, src, config)))
-                .collect(),
+            children: explore_children(node, src, config),
             identifier,
-            leading_white_space: node
-                .prev_sibling()
-                .map(|previous| &src[previous.end_byte()..node.start_byte()]),
+            leading_white_space,
             are_children_unordered: config.kinds_with_unordered_children.contains(node.kind()),
+            are_children_aligned: false,
             delimiters: config.delimiters.get(node.kind()),
             subtree_size_without_delimiters: OnceCell::new(),
             subtree_size: OnceCell::new(),
@@ -63,18 +63,102 @@ fn explore_node<'a>(node: Node, src: &'a str, config: &'a ParserConfiguration) -
     }
 }
 
+/// Explores the children of `node`. Trivia, such as comments, does not become a node: it is
+/// kept in the leading white space of the next sibling, so it is carried along with it without
+/// taking part in matching. Trivia with no following sibling is kept as a regular node.
+fn explore_children<'a>(
+    node: Node,
+    src: &'a str,
+    config: &'a ParserConfiguration,
+) -> Vec<CSTNode<'a>> {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+    let last_non_trivia_index = children
+        .iter()
+        .rposition(|child| !is_trivia(*child, src, config));
+
+    let mut explored = Vec::with_capacity(children.len());
+    let mut previous_end_byte = None;
+    for (index, child) in children.into_iter().enumerate() {
+        let is_followed_by_non_trivia = last_non_trivia_index.is_some_and(|last| index < last);
+        if is_trivia(child, src, config) && is_followed_by_non_trivia {
+            continue;
+        }
+
+        let leading_start_byte = match previous_end_byte {
+            Some(end_byte) => Some(end_byte),
+            None if index > 0 => Some(node.start_byte()),
+            None => None,
+        };
+        let leading_white_space = leading_start_byte.map(|start| &src[start..child.start_byte()]);
+        previous_end_byte = Some(rendered_end_byte(child, config));
+
+        explored.push(
+            config
+                .handlers
+                .run(explore_node(child, src, config, leading_white_space)),
+        );
+    }
+    explored
+}
+
+/// Whether `node` is carried by the next sibling instead of becoming a node, either because of
+/// its kind or because it is white space the grammar keeps as a node
+fn is_trivia(node: Node, src: &str, config: &ParserConfiguration) -> bool {
+    config.trivia_kinds.contains(node.kind())
+        || (config.white_space_kinds.contains(node.kind())
+            && src[node.byte_range()].trim().is_empty())
+}
+
+/// Where the text rendered for `node` ends. Some nodes span past their last child and take the
+/// line break ending them, such as C `#include` lines and Go statement lists. That text is not
+/// part of any terminal, so it is kept in the leading white space of the next sibling instead of
+/// being dropped, which used to glue the closing brace of Go blocks to their last statement.
+fn rendered_end_byte(node: Node, config: &ParserConfiguration) -> usize {
+    if node.child_count() == 0 || config.stop_compilation_at.contains(node.kind()) {
+        return node.end_byte();
+    }
+
+    node.child(node.child_count() - 1)
+        .map_or(node.end_byte(), |last_child| {
+            rendered_end_byte(last_child, config)
+        })
+}
+
+/// The result of parsing a source, along with the syntax errors the parser recovered from
+#[derive(Debug)]
+pub struct ParsedTree<'a> {
+    pub root: CSTNode<'a>,
+    /// The text after the last child of the root, such as the line break ending the file, which
+    /// no node carries
+    pub trailing_white_space: &'a str,
+    pub diagnostics: Vec<SyntaxDiagnostic>,
+}
+
+impl ParsedTree<'_> {
+    pub fn has_syntax_errors(&self) -> bool {
+        !self.diagnostics.is_empty()
+    }
+}
+
 pub fn parse_string<'a>(
     src: &'a str,
     config: &'a ParserConfiguration,
-) -> Result<CSTNode<'a>, &'static str> {
+) -> Result<ParsedTree<'a>, ParseError> {
     let mut parser = tree_sitter::Parser::new();
     parser
         .set_language(&config.language)
-        .map_err(|_| "There was an error while setting the parser language")?;
+        .map_err(ParseError::IncompatibleLanguage)?;
+
+    let parsed = parser.parse(src, None).ok_or(ParseError::TreeNotProduced)?;
+
+    let mut diagnostics = vec![];
+    collect_syntax_diagnostics(parsed.root_node(), &mut diagnostics);
 
-    let parsed = parser
-        .parse(src, None)
-        .ok_or("It was not possible to parse the tree.")?;
-    let root = explore_node(parsed.root_node(), src, config);
-    Ok(config.handlers.run(root))
+    let root = explore_node(parsed.root_node(), src, config, None);
+    Ok(ParsedTree {
+        root: config.handlers.run(root),
+        trailing_white_space: &src[rendered_end_byte(parsed.root_node(), config)..],
+        diagnostics,
+    })
 }
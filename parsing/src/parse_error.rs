@@ -0,0 +1,31 @@
+use std::error;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    IncompatibleLanguage(tree_sitter::LanguageError),
+    TreeNotProduced,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::IncompatibleLanguage(error) => {
+                write!(
+                    f,
+                    "There was an error while setting the parser language: {error}"
+                )
+            }
+            ParseError::TreeNotProduced => write!(f, "It was not possible to parse the tree"),
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ParseError::IncompatibleLanguage(error) => Some(error),
+            ParseError::TreeNotProduced => None,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+
+use model::Point;
+use tree_sitter::Node;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxDiagnosticKind {
+    /// Source the parser could not make sense of, held by an `ERROR` node
+    Error,
+    /// A node the parser assumed to be present in order to recover, such as a missing `;`
+    Missing(&'static str),
+}
+
+/// A syntax error found while parsing, located by the range of the offending node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxDiagnostic {
+    pub kind: SyntaxDiagnosticKind,
+    pub start_position: Point,
+    pub end_position: Point,
+    pub parent_kind: Option<&'static str>,
+}
+
+impl fmt::Display for SyntaxDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            SyntaxDiagnosticKind::Error => write!(f, "Syntax error")?,
+            SyntaxDiagnosticKind::Missing(kind) => write!(f, "Missing \"{kind}\"")?,
+        }
+        write!(
+            f,
+            " at {}:{}-{}:{}",
+            self.start_position.row + 1,
+            self.start_position.column + 1,
+            self.end_position.row + 1,
+            self.end_position.column + 1
+        )?;
+        if let Some(parent_kind) = self.parent_kind {
+            write!(f, " inside \"{parent_kind}\"")?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn collect_syntax_diagnostics(node: Node, diagnostics: &mut Vec<SyntaxDiagnostic>) {
+    let kind = if node.is_error() {
+        SyntaxDiagnosticKind::Error
+    } else if node.is_missing() {
+        SyntaxDiagnosticKind::Missing(node.kind())
+    } else {
+        if node.has_error() {
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                collect_syntax_diagnostics(child, diagnostics);
+            }
+        }
+        return;
+    };
+
+    diagnostics.push(SyntaxDiagnostic {
+        kind,
+        start_position: Point {
+            row: node.start_position().row,
+            column: node.start_position().column,
+        },
+        end_position: Point {
+            row: node.end_position().row,
+            column: node.end_position().column,
+        },
+        parent_kind: node.parent().map(|parent| parent.kind()),
+    });
+}
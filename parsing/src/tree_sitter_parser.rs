@@ -1,6 +1,6 @@
 use model::Language::JavaScript;
 use model::{cst_node::Delimiters, Language};
-use parsing_handlers::ParsingHandlers;
+use parsing_handlers::{ParsingHandler, ParsingHandlers};
 use std::collections::{HashMap, HashSet};
 
 use crate::identifier_extractor::IdentifierExtractor;
@@ -10,16 +10,30 @@ pub struct ParserConfiguration {
     pub(crate) language: tree_sitter::Language,
     pub(crate) stop_compilation_at: HashSet<&'static str>,
     pub(crate) kinds_with_unordered_children: HashSet<&'static str>,
+    /// Kinds attached as leading trivia of the following sibling instead of becoming nodes
+    pub(crate) trivia_kinds: HashSet<&'static str>,
+    /// Kinds taken as white space of the following sibling when they only hold white space, such
+    /// as XML character data between elements
+    pub(crate) white_space_kinds: HashSet<&'static str>,
     pub(crate) delimiters: HashMap<&'static str, Delimiters<'static>>,
     pub(crate) handlers: ParsingHandlers,
     pub(crate) identifier_extractors: HashMap<&'static str, Box<dyn IdentifierExtractor>>,
 }
 
+impl ParserConfiguration {
+    /// Runs `handler` after the handlers of the language, such as for normalisations a project
+    /// needs on its own trees
+    pub fn add_handler(&mut self, handler: impl ParsingHandler + 'static) {
+        self.handlers.push(handler);
+    }
+}
+
 impl From<Language> for ParserConfiguration {
     fn from(language: Language) -> Self {
+        let grammar = built_in_grammar(language);
         match language {
             Language::Java => ParserConfiguration {
-                language: tree_sitter_java::LANGUAGE.into(),
+                language: grammar.clone(),
                 stop_compilation_at: ["string_literal"].into(),
                 kinds_with_unordered_children: [
                     "interface_body",
@@ -28,13 +42,15 @@ impl From<Language> for ParserConfiguration {
                     "enum_body_declarations",
                 ]
                 .into(),
+                trivia_kinds: ["block_comment", "line_comment"].into(),
+                white_space_kinds: [].into(),
                 delimiters: HashMap::from([
                     ("interface_body", Delimiters::new("{", "}")),
                     ("class_body", Delimiters::new("{", "}")),
                 ]),
                 handlers: ParsingHandlers::from(Language::Java),
                 identifier_extractors: tree_sitter_queries_identifier_extractors! {
-                    language: tree_sitter_java::LANGUAGE,
+                    language: grammar.clone(),
                     queries: {
                         "constructor_declaration": r#"(constructor_declaration name: (identifier) @method_name parameters: (formal_parameters ([ (formal_parameter type: _@parameter_type) (spread_parameter (type_identifier) @parameter_type "..." @parameter_type _) ] "," ?) *))"#,
                         "method_declaration": r#"(method_declaration name: (identifier) @method_name parameters: (formal_parameters ([ (formal_parameter type: _@parameter_type) (spread_parameter (type_identifier) @parameter_type "..." @parameter_type _) ] "," ?) *))"#,
@@ -52,14 +68,16 @@ impl From<Language> for ParserConfiguration {
                 },
             },
             Language::CSharp => Self {
-                language: tree_sitter_c_sharp::LANGUAGE.into(),
+                language: grammar.clone(),
                 stop_compilation_at: HashSet::new(),
                 kinds_with_unordered_children: ["declaration_list", "enum_member_declaration_list"]
                     .into(),
+                trivia_kinds: [].into(),
+                white_space_kinds: [].into(),
                 delimiters: HashMap::from([("declaration_list", Delimiters::new("{", "}"))]),
                 handlers: ParsingHandlers::from(Language::CSharp),
                 identifier_extractors: tree_sitter_queries_identifier_extractors! {
-                    language: tree_sitter_c_sharp::LANGUAGE,
+                    language: grammar.clone(),
                     queries: {
                         "constructor_declaration": r#"(constructor_declaration name: (identifier) @method_name parameters: (parameter_list ([ (parameter type: _@parameter_type) ] "," ?) *))"#,
                         "method_declaration": r#"(method_declaration name: (identifier) @method_name parameters: (parameter_list ([ (parameter type: _@parameter_type) ] "," ?) *))"#,
@@ -67,28 +85,32 @@ impl From<Language> for ParserConfiguration {
                         "enum_declaration": r#"(enum_declaration (identifier) @class_name)"#,
                         "interface_declaration": r#"(interface_declaration (identifier) @class_name)"#,
                         "variable_declaration": r#"(variable_declarator (identifier) @name)"#,
+                        "using_directive": r#"(using_directive) @using"#,
                     }
                 },
             },
             Language::JavaScript => Self {
-                language: tree_sitter_javascript::LANGUAGE.into(),
+                language: grammar.clone(),
                 stop_compilation_at: [].into(),
                 kinds_with_unordered_children: HashSet::from(["object", "class_body"]),
+                trivia_kinds: [].into(),
+                white_space_kinds: [].into(),
                 delimiters: HashMap::from([
                     ("object", Delimiters::new_with_separator("{", "}", ",")),
                     ("class_body", Delimiters::new("{", "}")),
                 ]),
                 handlers: ParsingHandlers::from(JavaScript),
                 identifier_extractors: tree_sitter_queries_identifier_extractors! {
-                    language: tree_sitter_javascript::LANGUAGE,
+                    language: grammar.clone(),
                     queries: {
                         "pair": "(pair key: (_) @key)",
                         "method_definition": r#"(method_definition ["get" "set"]? @accessor name: (_) @name)"#,
+                        "import_statement": r#"(import_statement) @import"#,
                     }
                 },
             },
             Language::Go => Self {
-                language: tree_sitter_go::LANGUAGE.into(),
+                language: grammar.clone(),
                 stop_compilation_at: [].into(),
                 kinds_with_unordered_children: [
                     "import_spec_list",
@@ -100,6 +122,8 @@ impl From<Language> for ParserConfiguration {
                     "type_spec_list",             // This is synthetic (handler made)
                 ]
                 .into(),
+                trivia_kinds: [].into(),
+                white_space_kinds: [].into(),
                 delimiters: HashMap::from([
                     ("method_elem_list", Delimiters::new("{", "}")),
                     ("import_spec_list", Delimiters::new("(", ")")),
@@ -108,7 +132,7 @@ impl From<Language> for ParserConfiguration {
                 ]),
                 handlers: ParsingHandlers::from(Language::Go),
                 identifier_extractors: tree_sitter_queries_identifier_extractors! {
-                    language: tree_sitter_go::LANGUAGE,
+                    language: grammar.clone(),
                     queries: {
                         "method_elem": r#"(field_identifier) @method_name"#,
                         "method_declaration": r#"(method_declaration receiver: (parameter_list (parameter_declaration type: _ @receiver_name)) name: (field_identifier) @method_name)"#,
@@ -123,6 +147,337 @@ impl From<Language> for ParserConfiguration {
                     }
                 },
             },
+            Language::Python => Self {
+                language: grammar.clone(),
+                stop_compilation_at: ["string"].into(),
+                kinds_with_unordered_children: [
+                    "class_body",        // This is synthetic (handler made)
+                    "import_statements", // This is synthetic (handler made)
+                ]
+                .into(),
+                trivia_kinds: ["comment"].into(),
+                white_space_kinds: [].into(),
+                delimiters: HashMap::new(),
+                handlers: ParsingHandlers::from(Language::Python),
+                identifier_extractors: tree_sitter_queries_identifier_extractors! {
+                    language: grammar.clone(),
+                    queries: {
+                        "function_definition": r#"(function_definition name: (_) @name)"#,
+                        "class_definition": r#"(class_definition name: (_) @name)"#,
+                        "decorated_definition": r#"(decorated_definition definition: (_ name: (_) @name))"#,
+                        "expression_statement": r#"(expression_statement (assignment left: (_) @name))"#,
+                        "import_statement": r#"(import_statement) @import"#,
+                        "import_from_statement": r#"(import_from_statement) @import"#,
+                    }
+                },
+            },
+            Language::Rust => Self {
+                language: grammar.clone(),
+                stop_compilation_at: [
+                    "string_literal",
+                    "raw_string_literal",
+                    "char_literal",
+                    "line_comment",
+                    "block_comment",
+                    // Merged as a whole, so a changed receiver keeps its spacing
+                    "self_parameter",
+                ]
+                .into(),
+                kinds_with_unordered_children: [
+                    "declaration_list",
+                    "field_declaration_list",
+                    "enum_variant_list",
+                    "use_list",
+                    "use_declarations", // This is synthetic (handler made)
+                ]
+                .into(),
+                trivia_kinds: ["line_comment", "block_comment"].into(),
+                white_space_kinds: [].into(),
+                delimiters: HashMap::from([
+                    ("declaration_list", Delimiters::new("{", "}")),
+                    (
+                        "field_declaration_list",
+                        Delimiters::new_with_separator("{", "}", ","),
+                    ),
+                    (
+                        "enum_variant_list",
+                        Delimiters::new_with_separator("{", "}", ","),
+                    ),
+                    ("use_list", Delimiters::new_with_separator("{", "}", ",")),
+                ]),
+                handlers: ParsingHandlers::from(Language::Rust),
+                identifier_extractors: tree_sitter_queries_identifier_extractors! {
+                    language: grammar.clone(),
+                    queries: {
+                        "function_item": r#"(function_item name: (_) @name parameters: (parameters . (self_parameter)? @self_type))"#,
+                        "function_signature_item": r#"(function_signature_item name: (_) @name parameters: (parameters . (self_parameter)? @self_type))"#,
+                        "const_item": r#"(const_item name: (_) @name)"#,
+                        "type_item": r#"(type_item name: (_) @name)"#,
+                        "associated_type": r#"(associated_type name: (_) @name)"#,
+                        "field_declaration": r#"(field_declaration name: (_) @name)"#,
+                        "enum_variant": r#"(enum_variant name: (_) @name)"#,
+                        "use_declaration": r#"(use_declaration argument: [(scoped_use_list path: (_) @path) (identifier) @path (scoped_identifier) @path (use_as_clause) @path (use_wildcard) @path (use_list) @path])"#,
+                    }
+                },
+            },
+            Language::Kotlin => Self {
+                language: grammar.clone(),
+                stop_compilation_at: ["string_literal"].into(),
+                kinds_with_unordered_children: [
+                    "class_body",
+                    "import_list", // This is synthetic (handler made)
+                ]
+                .into(),
+                trivia_kinds: ["line_comment", "block_comment"].into(),
+                white_space_kinds: [].into(),
+                delimiters: HashMap::from([("class_body", Delimiters::new("{", "}"))]),
+                handlers: ParsingHandlers::from(Language::Kotlin),
+                identifier_extractors: tree_sitter_queries_identifier_extractors! {
+                    language: grammar.clone(),
+                    queries: {
+                        "function_declaration": r#"(function_declaration name: (_) @name (function_value_parameters ([(parameter (identifier) (_) @parameter_type) (parameter_modifiers)] ","?)*))"#,
+                        "property_declaration": r#"(property_declaration (variable_declaration (identifier) @name))"#,
+                        "companion_object": r#"(companion_object "companion" @companion)"#,
+                        "class_parameter": r#"(class_parameter (identifier) @name)"#,
+                        "class_declaration": r#"(class_declaration name: (_) @name)"#,
+                        "object_declaration": r#"(object_declaration name: (_) @name)"#,
+                        "import": r#"(import) @import"#,
+                    }
+                },
+            },
+            Language::C => Self {
+                language: grammar.clone(),
+                stop_compilation_at: ["string_literal", "char_literal"].into(),
+                kinds_with_unordered_children: [
+                    "field_declaration_list",
+                    "field_declaration_group", // This is synthetic (handler made)
+                    "preproc_include_block",   // This is synthetic (handler made)
+                ]
+                .into(),
+                trivia_kinds: ["comment"].into(),
+                white_space_kinds: [].into(),
+                delimiters: HashMap::from([("field_declaration_list", Delimiters::new("{", "}"))]),
+                handlers: ParsingHandlers::from(Language::C),
+                identifier_extractors: tree_sitter_queries_identifier_extractors! {
+                    language: grammar.clone(),
+                    queries: {
+                        "function_definition": r#"(function_definition declarator: [(function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (variadic_parameter) @parameter_type] ","?)*)) (pointer_declarator declarator: (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (variadic_parameter) @parameter_type] ","?)*)))])"#,
+                        "declaration": r#"(declaration declarator: [(function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (variadic_parameter) @parameter_type] ","?)*)) (pointer_declarator declarator: (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (variadic_parameter) @parameter_type] ","?)*)))])"#,
+                        "field_declaration": r#"(field_declaration declarator: [(field_identifier) @name (pointer_declarator declarator: (field_identifier) @name) (array_declarator declarator: (field_identifier) @name) (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (variadic_parameter) @parameter_type] ","?)*)) (pointer_declarator declarator: (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (variadic_parameter) @parameter_type] ","?)*)))])"#,
+                        "preproc_include": r#"(preproc_include path: (_) @path)"#,
+                        "preproc_ifdef": r##"(preproc_ifdef ["#ifdef" "#ifndef"] @directive name: (_) @name)"##,
+                    }
+                },
+            },
+            Language::Cpp => Self {
+                language: grammar.clone(),
+                stop_compilation_at: ["string_literal", "raw_string_literal", "char_literal"]
+                    .into(),
+                kinds_with_unordered_children: [
+                    "field_declaration_list",
+                    "field_declaration_group", // This is synthetic (handler made)
+                    "preproc_include_block",   // This is synthetic (handler made)
+                ]
+                .into(),
+                trivia_kinds: ["comment"].into(),
+                white_space_kinds: [].into(),
+                delimiters: HashMap::from([("field_declaration_list", Delimiters::new("{", "}"))]),
+                handlers: ParsingHandlers::from(Language::Cpp),
+                identifier_extractors: tree_sitter_queries_identifier_extractors! {
+                    language: grammar.clone(),
+                    queries: {
+                        "function_definition": r#"(function_definition declarator: [(function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (optional_parameter_declaration type: (_) @parameter_type) (variadic_parameter_declaration type: (_) @parameter_type) "..." @parameter_type] ","?)*)) (pointer_declarator declarator: (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (optional_parameter_declaration type: (_) @parameter_type) (variadic_parameter_declaration type: (_) @parameter_type) "..." @parameter_type] ","?)*))) (reference_declarator (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (optional_parameter_declaration type: (_) @parameter_type) (variadic_parameter_declaration type: (_) @parameter_type) "..." @parameter_type] ","?)*)))])"#,
+                        "declaration": r#"(declaration declarator: [(function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (optional_parameter_declaration type: (_) @parameter_type) (variadic_parameter_declaration type: (_) @parameter_type) "..." @parameter_type] ","?)*)) (pointer_declarator declarator: (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (optional_parameter_declaration type: (_) @parameter_type) (variadic_parameter_declaration type: (_) @parameter_type) "..." @parameter_type] ","?)*))) (reference_declarator (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (optional_parameter_declaration type: (_) @parameter_type) (variadic_parameter_declaration type: (_) @parameter_type) "..." @parameter_type] ","?)*)))])"#,
+                        "field_declaration": r#"(field_declaration declarator: [(field_identifier) @name (pointer_declarator declarator: (field_identifier) @name) (array_declarator declarator: (field_identifier) @name) (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (optional_parameter_declaration type: (_) @parameter_type) (variadic_parameter_declaration type: (_) @parameter_type) "..." @parameter_type] ","?)*)) (pointer_declarator declarator: (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (optional_parameter_declaration type: (_) @parameter_type) (variadic_parameter_declaration type: (_) @parameter_type) "..." @parameter_type] ","?)*))) (reference_declarator (function_declarator declarator: (_) @name parameters: (parameter_list ([(parameter_declaration type: (_) @parameter_type) (optional_parameter_declaration type: (_) @parameter_type) (variadic_parameter_declaration type: (_) @parameter_type) "..." @parameter_type] ","?)*)))])"#,
+                        "preproc_include": r#"(preproc_include path: (_) @path)"#,
+                        "preproc_ifdef": r##"(preproc_ifdef ["#ifdef" "#ifndef"] @directive name: (_) @name)"##,
+                    }
+                },
+            },
+            Language::Json => Self {
+                language: grammar.clone(),
+                stop_compilation_at: ["string"].into(),
+                kinds_with_unordered_children: ["object"].into(),
+                trivia_kinds: ["comment"].into(),
+                white_space_kinds: [].into(),
+                delimiters: HashMap::from([(
+                    "object",
+                    Delimiters::new_with_separator("{", "}", ","),
+                )]),
+                handlers: ParsingHandlers::from(Language::Json),
+                identifier_extractors: tree_sitter_queries_identifier_extractors! {
+                    language: grammar.clone(),
+                    queries: {
+                        "pair": "(pair key: (_) @key)",
+                    }
+                },
+            },
+            Language::Yaml => Self {
+                language: grammar.clone(),
+                stop_compilation_at: ["double_quote_scalar", "single_quote_scalar", "block_scalar"]
+                    .into(),
+                kinds_with_unordered_children: ["block_mapping"].into(),
+                trivia_kinds: ["comment"].into(),
+                white_space_kinds: [].into(),
+                delimiters: HashMap::new(),
+                handlers: ParsingHandlers::from(Language::Yaml),
+                identifier_extractors: tree_sitter_queries_identifier_extractors! {
+                    language: grammar.clone(),
+                    queries: {
+                        "block_mapping_pair": "(block_mapping_pair key: (_) @key)",
+                        "block_sequence_item": r#"(block_sequence_item (block_node (block_mapping (block_mapping_pair key: (_) @key value: (_) @name) (#eq? @key "name"))))"#,
+                    }
+                },
+            },
+            Language::Toml => Self {
+                language: grammar.clone(),
+                stop_compilation_at: ["string", "quoted_key"].into(),
+                kinds_with_unordered_children: [
+                    "document",
+                    "inline_table",
+                    "pairs", // This is synthetic (handler made)
+                ]
+                .into(),
+                trivia_kinds: ["comment"].into(),
+                white_space_kinds: [].into(),
+                delimiters: HashMap::from([(
+                    "inline_table",
+                    Delimiters::new_with_separator("{", "}", ","),
+                )]),
+                handlers: ParsingHandlers::from(Language::Toml),
+                identifier_extractors: tree_sitter_queries_identifier_extractors! {
+                    language: grammar.clone(),
+                    queries: {
+                        "table": "(table [(bare_key) (dotted_key) (quoted_key)] @header)",
+                        "pair": "(pair [(bare_key) (dotted_key) (quoted_key)] @key)",
+                    }
+                },
+            },
+            Language::Xml => Self {
+                language: grammar.clone(),
+                stop_compilation_at: ["AttValue", "CDSect"].into(),
+                kinds_with_unordered_children: ["STag", "EmptyElemTag"].into(),
+                trivia_kinds: ["Comment"].into(),
+                white_space_kinds: ["CharData"].into(),
+                delimiters: HashMap::from([
+                    ("STag", Delimiters::new("<", ">")),
+                    ("EmptyElemTag", Delimiters::new("<", "/>")),
+                ]),
+                handlers: ParsingHandlers::from(Language::Xml),
+                identifier_extractors: tree_sitter_queries_identifier_extractors! {
+                    language: grammar.clone(),
+                    queries: {
+                        "element": r#"
+                            (element (STag (Name) @tag) (content (element (STag (Name) @group_tag (#eq? @group_tag "groupId")) (content) @group) (element (STag (Name) @artifact_tag (#eq? @artifact_tag "artifactId")) (content) @artifact)))
+                            (element (STag (Name) @tag) (content (element (STag (Name) @artifact_tag (#eq? @artifact_tag "artifactId")) (content) @artifact)))
+                            (element (STag (Name) @tag) (content (element (STag (Name) @id_tag (#eq? @id_tag "id")) (content) @id)))
+                            (element (STag (Name) @tag (#eq? @tag "module")) . (content . (CharData) @module .))
+                            (element (STag (Name) @tag) . (content . (CharData) .))
+                            (element (EmptyElemTag (Name) @tag))
+                        "#,
+                        "Attribute": "(Attribute (Name) @name)",
+                    }
+                },
+            },
+            Language::Proto => Self {
+                language: grammar.clone(),
+                stop_compilation_at: ["string"].into(),
+                kinds_with_unordered_children: [
+                    "message_body",
+                    "enum_body",
+                    "service_body", // This is synthetic (handler made)
+                    "import_list",  // This is synthetic (handler made)
+                    "option_list",  // This is synthetic (handler made)
+                ]
+                .into(),
+                trivia_kinds: ["comment"].into(),
+                white_space_kinds: [].into(),
+                delimiters: HashMap::from([
+                    ("message_body", Delimiters::new("{", "}")),
+                    ("enum_body", Delimiters::new("{", "}")),
+                ]),
+                handlers: ParsingHandlers::from(Language::Proto),
+                identifier_extractors: tree_sitter_queries_identifier_extractors! {
+                    language: grammar.clone(),
+                    queries: {
+                        "message": "(message (message_name) @name)",
+                        "enum": "(enum (enum_name) @name)",
+                        "service": "(service (service_name) @name)",
+                        "field": "(field (identifier) @name)",
+                        "map_field": "(map_field (identifier) @name)",
+                        "oneof": "(oneof (identifier) @name)",
+                        "enum_field": "(enum_field (identifier) @name)",
+                        "rpc": "(rpc (rpc_name) @name)",
+                        "import": "(import path: (_) @path)",
+                        "option": r#"(option "option" . _+ @name . "=")"#,
+                    }
+                },
+            },
+            Language::TypeScript | Language::Tsx => typescript_configuration(grammar, language),
         }
     }
 }
+
+/// The tree-sitter grammar of a built-in language
+pub(crate) fn built_in_grammar(language: Language) -> tree_sitter::Language {
+    match language {
+        Language::Java => tree_sitter_java::LANGUAGE.into(),
+        Language::CSharp => tree_sitter_c_sharp::LANGUAGE.into(),
+        Language::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
+        Language::Go => tree_sitter_go::LANGUAGE.into(),
+        Language::Python => tree_sitter_python::LANGUAGE.into(),
+        Language::Rust => tree_sitter_rust::LANGUAGE.into(),
+        Language::Kotlin => tree_sitter_kotlin_ng::LANGUAGE.into(),
+        Language::C => tree_sitter_c::LANGUAGE.into(),
+        Language::Cpp => tree_sitter_cpp::LANGUAGE.into(),
+        Language::Json => tree_sitter_json::LANGUAGE.into(),
+        Language::Yaml => tree_sitter_yaml::LANGUAGE.into(),
+        Language::Toml => tree_sitter_toml_ng::LANGUAGE.into(),
+        Language::Xml => tree_sitter_xml::LANGUAGE_XML.into(),
+        Language::Proto => tree_sitter_proto::LANGUAGE.into(),
+        Language::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+        Language::Tsx => tree_sitter_typescript::LANGUAGE_TSX.into(),
+    }
+}
+
+/// TypeScript and TSX share their grammar, except for JSX expressions
+fn typescript_configuration(
+    tree_sitter_language: tree_sitter::Language,
+    language: Language,
+) -> ParserConfiguration {
+    ParserConfiguration {
+        language: tree_sitter_language.clone(),
+        stop_compilation_at: [].into(),
+        kinds_with_unordered_children: ["class_body", "object_type", "interface_body", "enum_body"]
+            .into(),
+        trivia_kinds: [].into(),
+        white_space_kinds: [].into(),
+        delimiters: HashMap::from([
+            ("class_body", Delimiters::new("{", "}")),
+            ("object_type", Delimiters::new_with_separator("{", "}", ",")),
+            (
+                "interface_body",
+                Delimiters::new_with_separator("{", "}", ","),
+            ),
+            ("enum_body", Delimiters::new_with_separator("{", "}", ",")),
+        ]),
+        handlers: ParsingHandlers::from(language),
+        identifier_extractors: tree_sitter_queries_identifier_extractors! {
+            language: tree_sitter_language.clone(),
+            queries: {
+                "pair": "(pair key: (_) @key)",
+                "method_definition": r#"(method_definition ["get" "set"]? @accessor name: (_) @name)"#,
+                "method_signature": r#"(method_signature ["get" "set"]? @accessor name: (_) @name)"#,
+                "abstract_method_signature": r#"(abstract_method_signature name: (_) @name)"#,
+                "public_field_definition": r#"(public_field_definition name: (_) @name)"#,
+                "property_signature": r#"(property_signature name: (_) @name)"#,
+                "class_declaration": r#"(class_declaration name: (_) @name)"#,
+                "abstract_class_declaration": r#"(abstract_class_declaration name: (_) @name)"#,
+                "interface_declaration": r#"(interface_declaration name: (_) @name)"#,
+                "type_alias_declaration": r#"(type_alias_declaration name: (_) @name)"#,
+                "enum_declaration": r#"(enum_declaration name: (_) @name)"#,
+                "enum_assignment": r#"(enum_assignment name: (_) @name)"#,
+            }
+        },
+    }
+}
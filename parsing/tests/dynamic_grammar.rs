@@ -0,0 +1,85 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use model::CSTNode;
+use parsing::{LanguageDefinition, LanguageDefinitionError, ParserConfiguration};
+
+/// Compiles the JSON grammar kept with the tests into a shared library, once per test run
+fn json_grammar_library() -> &'static Path {
+    static LIBRARY: OnceLock<PathBuf> = OnceLock::new();
+    LIBRARY.get_or_init(|| {
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/grammars/json");
+        let library = Path::new(env!("CARGO_TARGET_TMPDIR")).join("libtree-sitter-json-test.so");
+        let status = std::process::Command::new(std::env::var("CC").unwrap_or("cc".into()))
+            .args(["-shared", "-fPIC", "-std=c11", "-I"])
+            .arg(&source)
+            .arg(source.join("parser.c"))
+            .arg("-o")
+            .arg(&library)
+            .status()
+            .expect("A C compiler is needed to build the test grammar");
+        assert!(status.success());
+        library
+    })
+}
+
+#[test]
+fn a_grammar_loaded_from_a_library_is_paired_with_the_definition(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let directory = json_grammar_library().parent().unwrap();
+    let definition_path = directory.join("dynamic-json.toml");
+    std::fs::write(
+        &definition_path,
+        r#"
+name = "dynamic-json"
+grammar = "json"
+library = "libtree-sitter-json-test.so"
+kinds_with_unordered_children = ["object"]
+
+[identifier_queries]
+pair = "(pair key: (_) @key)"
+"#,
+    )?;
+
+    let definition = LanguageDefinition::from_file(&definition_path)?;
+    assert_eq!(definition.built_in_grammar(), None);
+
+    let config = ParserConfiguration::try_from(&definition)?;
+    let parsed = parsing::parse_string(r#"{"name": "last-merge"}"#, &config)?;
+
+    let CSTNode::NonTerminal(document) = &parsed.root else {
+        panic!("Expected the document to be a non terminal")
+    };
+    let CSTNode::NonTerminal(object) = &document.children[0] else {
+        panic!("Expected the object to be a non terminal")
+    };
+    assert!(object.are_children_unordered);
+    let CSTNode::NonTerminal(pair) = &object.children[1] else {
+        panic!("Expected the pair to be a non terminal")
+    };
+    assert_eq!(pair.get_identifier(), Some(&["\"name\""][..]));
+    Ok(())
+}
+
+#[test]
+fn a_grammar_symbol_missing_from_the_library_is_reported() {
+    assert!(matches!(
+        parsing::load_grammar(json_grammar_library(), "tree_sitter_scala"),
+        Err(LanguageDefinitionError::LoadingGrammar(_))
+    ));
+}
+
+#[test]
+fn a_missing_grammar_library_is_reported() {
+    let definition = LanguageDefinition {
+        name: "scala".into(),
+        grammar: "scala".into(),
+        library: Some("/path/to/missing/libtree-sitter-scala.so".into()),
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        ParserConfiguration::try_from(&definition),
+        Err(LanguageDefinitionError::LoadingGrammar(_))
+    ));
+}
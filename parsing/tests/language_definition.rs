@@ -0,0 +1,96 @@
+use model::CSTNode;
+use parsing::{LanguageDefinition, LanguageDefinitionError, ParserConfiguration};
+
+const JSON_DEFINITION: &str = r#"
+name = "package-json"
+grammar = "json"
+extensions = ["json"]
+stop_compilation_at = ["string"]
+kinds_with_unordered_children = ["object"]
+
+[delimiters]
+object = { start = "{", end = "}", separator = "," }
+
+[identifier_queries]
+pair = "(pair key: (_) @key)"
+"#;
+
+fn first_child<'a>(node: &'a CSTNode<'a>) -> &'a CSTNode<'a> {
+    match node {
+        CSTNode::NonTerminal(non_terminal) => &non_terminal.children[0],
+        CSTNode::Terminal(_) => panic!("Expected a non terminal"),
+    }
+}
+
+#[test]
+fn a_configuration_built_from_a_definition_follows_it() -> Result<(), Box<dyn std::error::Error>> {
+    let definition = LanguageDefinition::from_toml(JSON_DEFINITION)?;
+    let config = ParserConfiguration::try_from(&definition)?;
+    let parsed = parsing::parse_string(r#"{"name": "last-merge"}"#, &config)?;
+
+    let CSTNode::NonTerminal(object) = first_child(&parsed.root) else {
+        panic!("Expected the object to be a non terminal")
+    };
+    assert!(object.are_children_unordered);
+    assert_eq!(object.delimiters.and_then(|d| d.separator()), Some(","));
+
+    let CSTNode::NonTerminal(pair) = &object.children[1] else {
+        panic!("Expected the pair to be a non terminal")
+    };
+    assert_eq!(pair.get_identifier(), Some(&["\"name\""][..]));
+    Ok(())
+}
+
+#[test]
+fn definitions_can_also_be_written_in_json() -> Result<(), Box<dyn std::error::Error>> {
+    let definition = LanguageDefinition::from_json(
+        r#"{
+            "name": "java-ordered-members",
+            "grammar": "java",
+            "extensions": ["java"],
+            "identifier_queries": { "class_declaration": "(class_declaration (identifier) @name)" }
+        }"#,
+    )?;
+    let config = ParserConfiguration::try_from(&definition)?;
+    let parsed = parsing::parse_string("class Main { int x = 1; }", &config)?;
+
+    let CSTNode::NonTerminal(class) = first_child(&parsed.root) else {
+        panic!("Expected the class to be a non terminal")
+    };
+    assert_eq!(class.get_identifier(), Some(&["Main"][..]));
+    assert!(definition.applies_to(std::path::Path::new("src/Main.java")));
+    assert!(!definition.applies_to(std::path::Path::new("src/main.py")));
+    Ok(())
+}
+
+#[test]
+fn a_definition_with_an_unknown_grammar_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let definition = LanguageDefinition::from_toml("name = \"cobol\"\ngrammar = \"cobol\"")?;
+
+    assert!(matches!(
+        ParserConfiguration::try_from(&definition),
+        Err(LanguageDefinitionError::UnknownGrammar(grammar)) if grammar == "cobol"
+    ));
+    Ok(())
+}
+
+#[test]
+fn a_definition_with_an_invalid_query_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let definition = LanguageDefinition::from_toml(
+        "name = \"json\"\ngrammar = \"json\"\n[identifier_queries]\npair = \"(pair key:\"",
+    )?;
+
+    assert!(matches!(
+        ParserConfiguration::try_from(&definition),
+        Err(LanguageDefinitionError::InvalidQuery { kind, .. }) if kind == "pair"
+    ));
+    Ok(())
+}
+
+#[test]
+fn unknown_fields_in_a_definition_are_rejected() {
+    assert!(matches!(
+        LanguageDefinition::from_toml("name = \"json\"\ngrammar = \"json\"\nunordered = []"),
+        Err(LanguageDefinitionError::InvalidToml(_))
+    ));
+}
@@ -0,0 +1,61 @@
+use model::{CSTNode, Language};
+use parsing::ParserConfiguration;
+
+fn find_last<'a>(node: &'a CSTNode<'a>, kind: &str) -> Option<&'a CSTNode<'a>> {
+    match node {
+        _ if node.kind() == kind => Some(node),
+        CSTNode::NonTerminal(non_terminal) => non_terminal
+            .children
+            .iter()
+            .rev()
+            .find_map(|child| find_last(child, kind)),
+        CSTNode::Terminal(_) => None,
+    }
+}
+
+// Go statement lists span the line break ending their last statement, which belongs to no
+// terminal. It is kept before the closing brace rather than dropped.
+#[test]
+fn line_breaks_ending_a_go_block_stay_before_its_closing_brace(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = ParserConfiguration::from(Language::Go);
+    let parsed = parsing::parse_string("package main\n\nfunc Main() {\n\tz := 1\n}", &config)?;
+
+    let closing_brace = find_last(&parsed.root, "}").expect("Expected a closing brace");
+    assert_eq!(closing_brace.leading_white_space(), Some("\n"));
+    Ok(())
+}
+
+#[test]
+fn line_breaks_ending_a_c_include_stay_before_the_next_line(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = ParserConfiguration::from(Language::C);
+    let parsed = parsing::parse_string("#include <stdio.h>\nint x;", &config)?;
+
+    let declaration = find_last(&parsed.root, "declaration").expect("Expected a declaration");
+    assert_eq!(declaration.leading_white_space(), Some("\n"));
+    Ok(())
+}
+
+// XML keeps both element text and the white space between elements as character data. Only the
+// white space is carried by the next element: text takes part in matching like any other node.
+#[test]
+fn xml_text_stays_a_node_and_white_space_goes_with_the_next_element(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = ParserConfiguration::from(Language::Xml);
+    let parsed = parsing::parse_string("<a><b/>text<c/>\n  <d/>\n</a>", &config)?;
+
+    let text = find_last(&parsed.root, "CharData").expect("Expected character data");
+    assert_eq!(text.contents(), "text");
+
+    let Some(CSTNode::NonTerminal(content)) = find_last(&parsed.root, "content") else {
+        panic!("Expected the element to have content");
+    };
+    let last_element = content
+        .children
+        .last()
+        .expect("Expected the content to have children");
+    assert_eq!(last_element.kind(), "element");
+    assert_eq!(last_element.leading_white_space(), Some("\n  "));
+    Ok(())
+}
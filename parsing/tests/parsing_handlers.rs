@@ -0,0 +1,126 @@
+use model::{CSTNode, Language};
+use parsing::{rewrite_non_terminal, ParserConfiguration, ParsingHandler};
+
+/// Merges the children of the configured kinds unordered
+struct UnorderedKinds {
+    kinds: Vec<&'static str>,
+}
+
+impl ParsingHandler for UnorderedKinds {
+    fn name(&self) -> &str {
+        "unordered_kinds"
+    }
+
+    fn kinds(&self) -> Option<&[&str]> {
+        Some(&self.kinds)
+    }
+
+    fn handle<'a>(&self, node: CSTNode<'a>) -> CSTNode<'a> {
+        match node {
+            CSTNode::NonTerminal(non_terminal) => {
+                rewrite_non_terminal(non_terminal, |non_terminal| {
+                    non_terminal.are_children_unordered = true
+                })
+            }
+            CSTNode::Terminal(_) => node,
+        }
+    }
+}
+
+fn find<'a>(node: &'a CSTNode<'a>, kind: &str) -> Option<&'a CSTNode<'a>> {
+    match node {
+        _ if node.kind() == kind => Some(node),
+        CSTNode::NonTerminal(non_terminal) => non_terminal
+            .children
+            .iter()
+            .find_map(|child| find(child, kind)),
+        CSTNode::Terminal(_) => None,
+    }
+}
+
+#[test]
+fn handlers_added_to_a_configuration_run_on_the_nodes_of_their_kinds(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = ParserConfiguration::from(Language::Java);
+    config.add_handler(UnorderedKinds {
+        kinds: vec!["argument_list"],
+    });
+
+    let parsed = parsing::parse_string(
+        "class Main { void run() { call(a, b); call(c); } }",
+        &config,
+    )?;
+
+    let Some(CSTNode::NonTerminal(arguments)) = find(&parsed.root, "argument_list") else {
+        panic!("Expected an argument list")
+    };
+    assert!(arguments.are_children_unordered);
+    let Some(CSTNode::NonTerminal(parameters)) = find(&parsed.root, "formal_parameters") else {
+        panic!("Expected the method parameters")
+    };
+    assert!(!parameters.are_children_unordered);
+    Ok(())
+}
+
+#[test]
+fn xml_elements_named_in_the_overrides_have_unordered_children(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = ParserConfiguration::from(Language::Xml);
+    config.apply_overrides(&parsing::ConfigurationOverrides {
+        unordered_elements: vec!["executions".into()],
+        ..Default::default()
+    })?;
+
+    let parsed = parsing::parse_string(
+        "<plugin><executions><execution/></executions></plugin>",
+        &config,
+    )?;
+
+    let Some(CSTNode::NonTerminal(plugin)) = find(&parsed.root, "content") else {
+        panic!("Expected the content of the plugin")
+    };
+    assert!(!plugin.are_children_unordered);
+    let Some(CSTNode::NonTerminal(executions)) = plugin
+        .children
+        .iter()
+        .find_map(|child| find(child, "content"))
+    else {
+        panic!("Expected the content of the executions")
+    };
+    assert!(executions.are_children_unordered);
+    Ok(())
+}
+
+#[test]
+fn unordered_elements_are_rejected_outside_xml() {
+    let mut config = ParserConfiguration::from(Language::Java);
+
+    let result = config.apply_overrides(&parsing::ConfigurationOverrides {
+        unordered_elements: vec!["executions".into()],
+        ..Default::default()
+    });
+
+    assert_eq!(
+        result,
+        Err(parsing::ConfigurationOverridesError::UnorderedElementsOutsideXml)
+    );
+}
+
+#[test]
+fn unordered_elements_are_left_out_if_their_handler_is_disabled(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = ParserConfiguration::from(Language::Xml);
+    config.apply_overrides(&parsing::ConfigurationOverrides {
+        handlers: Some(vec!["attach_closing_indentation".into()]),
+        unordered_elements: vec!["executions".into()],
+        ..Default::default()
+    })?;
+
+    let parsed = parsing::parse_string("<executions><execution/></executions>", &config)?;
+
+    let Some(CSTNode::NonTerminal(executions)) = find(&parsed.root, "content") else {
+        panic!("Expected the content of the executions")
+    };
+    assert!(!executions.are_children_unordered);
+    Ok(())
+}
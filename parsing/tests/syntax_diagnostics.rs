@@ -0,0 +1,42 @@
+use model::{language::Language, Point};
+use parsing::{ParserConfiguration, SyntaxDiagnostic, SyntaxDiagnosticKind};
+
+#[test]
+fn a_valid_source_has_no_syntax_diagnostics() -> Result<(), Box<dyn std::error::Error>> {
+    let config = ParserConfiguration::from(Language::Java);
+    let parsed = parsing::parse_string("class Main { int x = 1; }", &config)?;
+
+    assert!(!parsed.has_syntax_errors());
+    Ok(())
+}
+
+#[test]
+fn missing_nodes_are_reported_with_their_position_and_parent(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = ParserConfiguration::from(Language::Java);
+    let parsed = parsing::parse_string("class Main {\n  int x = 1\n}", &config)?;
+
+    assert_eq!(
+        parsed.diagnostics,
+        vec![SyntaxDiagnostic {
+            kind: SyntaxDiagnosticKind::Missing(";"),
+            start_position: Point { row: 1, column: 11 },
+            end_position: Point { row: 1, column: 11 },
+            parent_kind: Some("field_declaration"),
+        }]
+    );
+    Ok(())
+}
+
+#[test]
+fn error_nodes_are_reported_with_their_position() -> Result<(), Box<dyn std::error::Error>> {
+    let config = ParserConfiguration::from(Language::Java);
+    let parsed = parsing::parse_string("class Main {\n  int x = = 1;\n}", &config)?;
+
+    assert!(parsed
+        .diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.kind == SyntaxDiagnosticKind::Error
+            && diagnostic.start_position.row == 1));
+    Ok(())
+}